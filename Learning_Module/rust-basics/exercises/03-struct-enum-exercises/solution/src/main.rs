@@ -8,8 +8,14 @@ enum ItemType {
     Armor { defense: u32 },
     Consumable { healing: u32 },
     Quest,
+    Container { capacity: u32, contents: Vec<Item> },
+    LiquidContainer { capacity_ml: u32, contents: Vec<(String, u32)> },
 }
 
+// Maximum total weight (weight * quantity, summed recursively through
+// containers) the inventory can carry before InventoryAction::Add rejects.
+const MAX_CARRY_WEIGHT: u32 = 500;
+
 // Define the Item struct
 #[derive(Debug, Clone)]
 struct Item {
@@ -17,20 +23,31 @@ struct Item {
     item_type: ItemType,
     quantity: u32,
     value: u32,
+    weight: u32,
+    // Ticks remaining before a consumable spoils; None for items that don't decay.
+    freshness: Option<u32>,
 }
 
 // Implement methods on Item
 impl Item {
     // Associated function (constructor)
-    fn new(name: String, item_type: ItemType, quantity: u32, value: u32) -> Self {
+    fn new(name: String, item_type: ItemType, quantity: u32, value: u32, weight: u32) -> Self {
         Self {
             name,
             item_type,
             quantity,
             value,
+            weight,
+            freshness: None,
         }
     }
-    
+
+    // Builder: give the item a freshness counter that ticks down via apply_tick
+    fn with_freshness(mut self, freshness: u32) -> Self {
+        self.freshness = Some(freshness);
+        self
+    }
+
     // Calculate total value (value * quantity)
     fn total_value(&self) -> u32 {
         self.value * self.quantity
@@ -38,10 +55,25 @@ impl Item {
     
     // Return a formatted description
     fn description(&self) -> String {
+        if let ItemType::LiquidContainer { contents, .. } = &self.item_type {
+            let total: u32 = contents.iter().map(|(_, volume)| *volume).sum();
+            let mix = contents
+                .iter()
+                .map(|(liquid, volume)| format!("{} {}", volume, liquid))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return format!("{} ({}ml: {})", self.name, total, mix);
+        }
+
         let type_name = get_item_type_name(&self.item_type);
+        let display_name = if self.quantity != 1 {
+            pluralise(&self.name)
+        } else {
+            self.name.clone()
+        };
         format!(
             "{} ({}) - Quantity: {}, Value: {} gold, Total: {} gold",
-            self.name,
+            display_name,
             type_name,
             self.quantity,
             self.value,
@@ -56,6 +88,10 @@ enum InventoryAction {
     Add { item: Item },
     Remove { name: String, quantity: u32 },
     Use { name: String },
+    PutInto { item_name: String, container_name: String },
+    TakeFrom { item_name: String, container_name: String },
+    Fill { container_name: String, source_name: String, amount: u32 },
+    Drink { container_name: String, amount: u32 },
     List,
 }
 
@@ -63,10 +99,17 @@ enum InventoryAction {
 fn process_action(action: InventoryAction, inventory: &mut Vec<Item>) {
     match action {
         InventoryAction::Add { item } => {
-            // Check if item already exists
-            if let Some(existing_item) = inventory.iter_mut().find(|i| i.name == item.name) {
+            let prospective_weight = recalculate_weight(inventory) + item.weight * item.quantity;
+
+            if prospective_weight > MAX_CARRY_WEIGHT {
+                println!(
+                    "Cannot add {} - would exceed carry capacity ({}/{})",
+                    item.name, prospective_weight, MAX_CARRY_WEIGHT
+                );
+            } else if let Some(existing_item) = inventory.iter_mut().find(|i| i.name == item.name) {
                 existing_item.quantity += item.quantity;
-                println!("Added {} {}(s) to existing stack", item.quantity, item.name);
+                let name = if item.quantity != 1 { pluralise(&item.name) } else { item.name.clone() };
+                println!("Added {} {} to existing stack", item.quantity, name);
             } else {
                 println!("Added new item: {}", item.name);
                 inventory.push(item);
@@ -77,7 +120,8 @@ fn process_action(action: InventoryAction, inventory: &mut Vec<Item>) {
             if let Some(item) = inventory.iter_mut().find(|i| i.name == name) {
                 if item.quantity >= quantity {
                     item.quantity -= quantity;
-                    println!("Removed {} {}(s)", quantity, name);
+                    let display_name = if quantity != 1 { pluralise(&name) } else { name.clone() };
+                    println!("Removed {} {}", quantity, display_name);
                     
                     // Remove item if quantity reaches 0
                     if item.quantity == 0 {
@@ -85,7 +129,11 @@ fn process_action(action: InventoryAction, inventory: &mut Vec<Item>) {
                         println!("{} removed from inventory (quantity reached 0)", name);
                     }
                 } else {
-                    println!("Cannot remove {} {}(s) - only {} available", quantity, name, item.quantity);
+                    let display_name = if quantity != 1 { pluralise(&name) } else { name.clone() };
+                    println!(
+                        "Cannot remove {} {} - only {} available",
+                        quantity, display_name, item.quantity
+                    );
                 }
             } else {
                 println!("Item '{}' not found in inventory", name);
@@ -116,20 +164,188 @@ fn process_action(action: InventoryAction, inventory: &mut Vec<Item>) {
             }
         }
         
+        InventoryAction::PutInto { item_name, container_name } => {
+            let item_pos = inventory.iter().position(|i| i.name == item_name);
+            let container_pos = inventory.iter().position(|i| i.name == container_name);
+
+            match (item_pos, container_pos) {
+                (Some(item_pos), Some(container_pos)) if item_pos == container_pos => {
+                    println!("Cannot put {} into itself", item_name);
+                }
+                (Some(item_pos), Some(container_pos)) => {
+                    let capacity_check = match &inventory[container_pos].item_type {
+                        ItemType::Container { capacity, contents } => {
+                            Some((contents.len() as u32) < *capacity)
+                        }
+                        _ => None,
+                    };
+
+                    match capacity_check {
+                        None => println!("{} is not a container", container_name),
+                        Some(false) => println!("{} is full", container_name),
+                        Some(true) => {
+                            let item = inventory.remove(item_pos);
+                            // Removing `item_pos` may have shifted `container_pos`.
+                            let container_pos = if item_pos < container_pos {
+                                container_pos - 1
+                            } else {
+                                container_pos
+                            };
+
+                            if let ItemType::Container { contents, .. } =
+                                &mut inventory[container_pos].item_type
+                            {
+                                println!("Put {} into {}", item_name, container_name);
+                                contents.push(item);
+                            }
+                        }
+                    }
+                }
+                (None, _) => println!("Item '{}' not found in inventory", item_name),
+                (_, None) => println!("Container '{}' not found in inventory", container_name),
+            }
+        }
+
+        InventoryAction::TakeFrom { item_name, container_name } => {
+            match inventory.iter_mut().find(|i| i.name == container_name) {
+                Some(container) => match &mut container.item_type {
+                    ItemType::Container { contents, .. } => {
+                        match contents.iter().position(|i| i.name == item_name) {
+                            Some(pos) => {
+                                let item = contents.remove(pos);
+                                println!("Took {} from {}", item_name, container_name);
+
+                                if let Some(existing) =
+                                    inventory.iter_mut().find(|i| i.name == item.name)
+                                {
+                                    existing.quantity += item.quantity;
+                                } else {
+                                    inventory.push(item);
+                                }
+                            }
+                            None => println!(
+                                "'{}' not found in {}",
+                                item_name, container_name
+                            ),
+                        }
+                    }
+                    _ => println!("{} is not a container", container_name),
+                },
+                None => println!("Container '{}' not found in inventory", container_name),
+            }
+        }
+
+        InventoryAction::Fill { container_name, source_name, amount } => {
+            match inventory.iter_mut().find(|i| i.name == container_name) {
+                Some(container) => match &mut container.item_type {
+                    ItemType::LiquidContainer { capacity_ml, contents } => {
+                        let current_total: u32 = contents.iter().map(|(_, volume)| *volume).sum();
+                        let actual = amount.min(capacity_ml.saturating_sub(current_total));
+
+                        if actual == 0 {
+                            println!("{} is full", container_name);
+                        } else {
+                            match contents.iter_mut().find(|(liquid, _)| *liquid == source_name) {
+                                Some(entry) => entry.1 += actual,
+                                None => contents.push((source_name.clone(), actual)),
+                            }
+                            println!("Filled {} with {}ml of {}", container_name, actual, source_name);
+                        }
+                    }
+                    _ => println!("{} is not a liquid container", container_name),
+                },
+                None => println!("Item '{}' not found in inventory", container_name),
+            }
+        }
+
+        InventoryAction::Drink { container_name, amount } => {
+            match inventory.iter_mut().find(|i| i.name == container_name) {
+                Some(container) => match &mut container.item_type {
+                    ItemType::LiquidContainer { contents, .. } => {
+                        let mut remaining = amount;
+                        for (_, volume) in contents.iter_mut() {
+                            let drunk = remaining.min(*volume);
+                            *volume -= drunk;
+                            remaining -= drunk;
+                        }
+                        contents.retain(|(_, volume)| *volume > 0);
+                        println!("Drank {}ml from {}", amount - remaining, container_name);
+                    }
+                    _ => println!("{} is not a liquid container", container_name),
+                },
+                None => println!("Item '{}' not found in inventory", container_name),
+            }
+        }
+
         InventoryAction::List => {
             if inventory.is_empty() {
                 println!("Inventory is empty");
             } else {
                 println!("\nCurrent Inventory:");
-                for (index, item) in inventory.iter().enumerate() {
-                    println!("{}. {}", index + 1, item.description());
-                }
-                
+                print_items(inventory, 0);
+
                 let total_value: u32 = inventory.iter().map(|i| i.total_value()).sum();
+                let total_weight = recalculate_weight(inventory);
                 println!("\nTotal Inventory Value: {} gold", total_value);
+                println!("Carry Weight: {}/{}", total_weight, MAX_CARRY_WEIGHT);
+            }
+        }
+    }
+}
+
+// Print items, recursing into container contents with increasing indentation
+fn print_items(items: &[Item], indent: usize) {
+    let prefix = "  ".repeat(indent);
+    for (index, item) in items.iter().enumerate() {
+        println!("{}{}. {}", prefix, index + 1, item.description());
+        if let ItemType::Container { contents, .. } = &item.item_type {
+            print_items(contents, indent + 1);
+        }
+    }
+}
+
+// Sum weight * quantity across all items, recursing into container contents
+fn recalculate_weight(items: &[Item]) -> u32 {
+    items
+        .iter()
+        .map(|item| {
+            let own_weight = item.weight * item.quantity;
+            let nested_weight = match &item.item_type {
+                ItemType::Container { contents, .. } => recalculate_weight(contents),
+                _ => 0,
+            };
+            own_weight + nested_weight
+        })
+        .sum()
+}
+
+// Decrements every item's freshness counter by one tick (recursing into
+// container contents). Items that hit zero spoil - their healing is lost
+// and the counter stops ticking - and a human-readable event is returned
+// for each transition so a caller can report what happened.
+fn apply_tick(items: &mut [Item]) -> Vec<String> {
+    let mut events = Vec::new();
+
+    for item in items.iter_mut() {
+        if let Some(last_value) = item.freshness {
+            let decayed = last_value.saturating_sub(1);
+            if decayed == 0 {
+                if let ItemType::Consumable { healing } = &mut item.item_type {
+                    *healing = 0;
+                }
+                item.freshness = None;
+                events.push(format!("{} spoiled", item.name));
+            } else {
+                item.freshness = Some(decayed);
             }
         }
+
+        if let ItemType::Container { contents, .. } = &mut item.item_type {
+            events.extend(apply_tick(contents));
+        }
     }
+
+    events
 }
 
 // Get the name of an item type using pattern matching
@@ -139,7 +355,53 @@ fn get_item_type_name(item_type: &ItemType) -> &str {
         ItemType::Armor { .. } => "Armor",
         ItemType::Consumable { .. } => "Consumable",
         ItemType::Quest => "Quest Item",
+        ItemType::Container { .. } => "Container",
+        ItemType::LiquidContainer { .. } => "Liquid Container",
+    }
+}
+
+// A single irregular pluralisation rule: drop the last `drop` characters of
+// a name ending in `match_suffix`, then append `append_suffix`.
+struct PluralRule {
+    match_suffix: &'static str,
+    drop: usize,
+    append_suffix: &'static str,
+}
+
+// Lazily-built irregular rule table, most specific suffix first.
+fn plural_rules() -> &'static Vec<PluralRule> {
+    static RULES: std::sync::OnceLock<Vec<PluralRule>> = std::sync::OnceLock::new();
+    RULES.get_or_init(|| {
+        vec![
+            PluralRule { match_suffix: "foot", drop: 4, append_suffix: "feet" },
+            PluralRule { match_suffix: "tooth", drop: 5, append_suffix: "teeth" },
+            PluralRule { match_suffix: "man", drop: 3, append_suffix: "men" },
+            PluralRule { match_suffix: "mouse", drop: 5, append_suffix: "mice" },
+            PluralRule { match_suffix: "fish", drop: 0, append_suffix: "" },
+            PluralRule { match_suffix: "sheep", drop: 0, append_suffix: "" },
+            PluralRule { match_suffix: "deer", drop: 0, append_suffix: "" },
+        ]
+    })
+}
+
+// Pluralises `name`, handling a trailing "<head> of <descriptor>" split so
+// only the head noun changes (e.g. "pair of gloves" -> "pairs of gloves").
+fn pluralise(name: &str) -> String {
+    match name.split_once(" of ") {
+        Some((head, tail)) => format!("{} of {}", pluralise_word(head), tail),
+        None => pluralise_word(name),
+    }
+}
+
+fn pluralise_word(word: &str) -> String {
+    let lower = word.to_lowercase();
+    for rule in plural_rules() {
+        if lower.ends_with(rule.match_suffix) {
+            let stem = &word[..word.len() - rule.drop];
+            return format!("{}{}", stem, rule.append_suffix);
+        }
     }
+    format!("{}s", word)
 }
 
 fn main() {
@@ -153,28 +415,32 @@ fn main() {
         String::from("Iron Sword"),
         ItemType::Weapon { damage: 25 },
         1,
-        50
+        50,
+        10
     );
-    
+
     let armor = Item::new(
         String::from("Leather Armor"),
         ItemType::Armor { defense: 15 },
         1,
-        75
+        75,
+        15
     );
-    
+
     let potion = Item::new(
         String::from("Health Potion"),
         ItemType::Consumable { healing: 50 },
         5,
-        20
-    );
-    
+        20,
+        1
+    ).with_freshness(1);
+
     let quest_item = Item::new(
         String::from("Ancient Map"),
         ItemType::Quest,
         1,
-        0
+        0,
+        1
     );
     
     // Add items to inventory
@@ -208,8 +474,91 @@ fn main() {
     
     // Try to remove more items than available
     println!("\nTrying to remove 10 Health Potions...");
-    process_action(InventoryAction::Remove { 
-        name: String::from("Health Potion"), 
-        quantity: 10 
+    process_action(InventoryAction::Remove {
+        name: String::from("Health Potion"),
+        quantity: 10
     }, &mut inventory);
+
+    // Containers
+    println!("\nAdding a chest and storing the map inside it...");
+    let chest = Item::new(
+        String::from("Wooden Chest"),
+        ItemType::Container { capacity: 2, contents: Vec::new() },
+        1,
+        10,
+        5
+    );
+    process_action(InventoryAction::Add { item: chest }, &mut inventory);
+    process_action(InventoryAction::PutInto {
+        item_name: String::from("Ancient Map"),
+        container_name: String::from("Wooden Chest"),
+    }, &mut inventory);
+    process_action(InventoryAction::List, &mut inventory);
+
+    println!("\nTaking the map back out of the chest...");
+    process_action(InventoryAction::TakeFrom {
+        item_name: String::from("Ancient Map"),
+        container_name: String::from("Wooden Chest"),
+    }, &mut inventory);
+    process_action(InventoryAction::List, &mut inventory);
+
+    // Let the Health Potion's freshness counter tick down to zero
+    println!("\nApplying a tick...");
+    for event in apply_tick(&mut inventory) {
+        println!("{}", event);
+    }
+    process_action(InventoryAction::List, &mut inventory);
+
+    // Liquid containers
+    println!("\nFilling a flask with water and juice...");
+    let flask = Item::new(
+        String::from("Flask"),
+        ItemType::LiquidContainer { capacity_ml: 500, contents: Vec::new() },
+        1,
+        5,
+        1
+    );
+    process_action(InventoryAction::Add { item: flask }, &mut inventory);
+    process_action(InventoryAction::Fill {
+        container_name: String::from("Flask"),
+        source_name: String::from("water"),
+        amount: 200,
+    }, &mut inventory);
+    process_action(InventoryAction::Fill {
+        container_name: String::from("Flask"),
+        source_name: String::from("juice"),
+        amount: 100,
+    }, &mut inventory);
+    process_action(InventoryAction::List, &mut inventory);
+
+    println!("\nDrinking 150ml from the flask...");
+    process_action(InventoryAction::Drink {
+        container_name: String::from("Flask"),
+        amount: 150,
+    }, &mut inventory);
+    process_action(InventoryAction::List, &mut inventory);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pluralise_handles_irregular_and_regular_words() {
+        let cases = [
+            ("foot", "feet"),
+            ("tooth", "teeth"),
+            ("man", "men"),
+            ("mouse", "mice"),
+            ("fish", "fish"),
+            ("sheep", "sheep"),
+            ("deer", "deer"),
+            ("sword", "swords"),
+            ("pair of gloves", "pairs of gloves"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(pluralise(input), expected, "pluralising {:?}", input);
+        }
+    }
 }
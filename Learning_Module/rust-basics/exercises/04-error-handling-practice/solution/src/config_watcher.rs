@@ -0,0 +1,114 @@
+// Hot-reload subsystem: watches a TOML config file and re-validates it on
+// every change, swapping in the new `Config` only if it passes the same
+// validation pipeline as `Config::new`. Modeled on the settings-hot-reload
+// pattern common in mail servers - a background thread owns the watcher,
+// callers only ever see the last-good config plus a feed of reload events.
+
+use crate::{Config, ConfigError};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+/// How long to wait after an fs event before reloading, coalescing the
+/// burst of events an editor typically emits for a single save.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Watches a TOML config file in the background, keeping an always-valid
+/// `Config` available to callers and notifying subscribers each time a
+/// reload succeeds. Dropping this drops the underlying `notify` watcher,
+/// which stops the background thread.
+pub struct ConfigWatcher {
+    current: Arc<RwLock<Config>>,
+    subscribers: Arc<Mutex<Vec<Sender<Config>>>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    /// Loads and validates `path` once up front, then starts watching it for
+    /// changes. Fails the same way `Config::from_file` does if the initial
+    /// load doesn't validate.
+    pub fn new(path: impl Into<PathBuf>) -> Result<ConfigWatcher, ConfigError> {
+        let path = path.into();
+        let initial = Config::from_file(path.to_str().unwrap_or_default())?;
+        let current = Arc::new(RwLock::new(initial));
+        let subscribers: Arc<Mutex<Vec<Sender<Config>>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let (event_tx, event_rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |res| {
+            let _ = event_tx.send(res);
+        })
+        .map_err(|e| ConfigError::ParseError {
+            field: "watcher".to_string(),
+            message: e.to_string(),
+            source: Some(Box::new(e)),
+        })?;
+        watcher
+            .watch(&path, RecursiveMode::NonRecursive)
+            .map_err(|e| ConfigError::ParseError {
+                field: "watcher".to_string(),
+                message: e.to_string(),
+                source: Some(Box::new(e)),
+            })?;
+
+        let reload_current = Arc::clone(&current);
+        let reload_subscribers = Arc::clone(&subscribers);
+        thread::spawn(move || run_reload_loop(path, event_rx, reload_current, reload_subscribers));
+
+        Ok(ConfigWatcher {
+            current,
+            subscribers,
+            _watcher: watcher,
+        })
+    }
+
+    /// Reads the current, last-known-good config.
+    pub fn current(&self) -> Config {
+        self.current
+            .read()
+            .expect("config lock poisoned")
+            .clone()
+    }
+
+    /// Subscribes to future successful reloads. The returned `Receiver`
+    /// gets one message per reload from this point on.
+    pub fn subscribe(&self) -> Receiver<Config> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers
+            .lock()
+            .expect("subscribers lock poisoned")
+            .push(tx);
+        rx
+    }
+}
+
+/// Waits for fs events, debounces bursts, and reloads+validates on each
+/// settled burst, swapping in the new config and notifying subscribers only
+/// on success. Runs until the event channel's sender is dropped (i.e. the
+/// `ConfigWatcher`, and its `notify::Watcher`, is dropped).
+fn run_reload_loop(
+    path: PathBuf,
+    event_rx: Receiver<notify::Result<Event>>,
+    current: Arc<RwLock<Config>>,
+    subscribers: Arc<Mutex<Vec<Sender<Config>>>>,
+) {
+    while event_rx.recv().is_ok() {
+        // Drain any further events arriving within the debounce window so a
+        // burst of writes triggers a single reload.
+        while event_rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+        match Config::from_file(path.to_str().unwrap_or_default()) {
+            Ok(new_config) => {
+                *current.write().expect("config lock poisoned") = new_config.clone();
+
+                let mut subs = subscribers.lock().expect("subscribers lock poisoned");
+                subs.retain(|tx| tx.send(new_config.clone()).is_ok());
+            }
+            Err(e) => {
+                eprintln!("Config reload failed, keeping last-good config: {}", e);
+            }
+        }
+    }
+}
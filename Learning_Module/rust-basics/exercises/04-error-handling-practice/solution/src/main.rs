@@ -1,19 +1,38 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+mod config_watcher;
+use config_watcher::ConfigWatcher;
 
 // Custom error type for configuration errors
 #[derive(Debug)]
 enum ConfigError {
-    ParseError { field: String, message: String },
+    ParseError {
+        field: String,
+        message: String,
+        // The underlying cause (a parse failure, an I/O error, a TOML parse
+        // error, ...), kept around so callers can inspect it via `source()`
+        // instead of only seeing the flattened message.
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
     ValidationError { field: String, message: String },
     MissingField { field: String },
     InvalidRange { field: String, min: i32, max: i32, actual: i32 },
+    UnsupportedVersion { found: u32, max_supported: u32 },
 }
 
 // Implement Display trait for user-friendly error messages
 impl fmt::Display for ConfigError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ConfigError::ParseError { field, message } => {
+            ConfigError::ParseError { field, message, .. } => {
                 write!(f, "Failed to parse field '{}': {}", field, message)
             }
             ConfigError::ValidationError { field, message } => {
@@ -29,13 +48,72 @@ impl fmt::Display for ConfigError {
                     field, actual, min, max
                 )
             }
+            ConfigError::UnsupportedVersion { found, max_supported } => {
+                write!(
+                    f,
+                    "Config schema version {} is newer than the max supported version {}",
+                    found, max_supported
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::ParseError { source, .. } => {
+                source.as_deref().map(|e| e as &(dyn std::error::Error + 'static))
+            }
+            _ => None,
         }
     }
 }
 
 // Configuration struct with required and optional fields
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Config {
+    // Always `CURRENT_CONFIG_VERSION` once a `Config` has been built, since
+    // `Config::new`/`Config::from_file` migrate everything up to it first.
+    version: u32,
+    server_name: String,
+    port: u16,
+    max_connections: u32,
+    timeout_seconds: Option<u32>,
+    admin_email: Option<String>,
+    // Whether `admin_email`'s local part had its `+tag` subaddress stripped
+    // on load; kept so a re-saved config doesn't flip-flop on this choice.
+    strip_subaddress: bool,
+}
+
+/// The schema version this build of `Config` understands. Bump this and add
+/// a `migrate_vN_to_vN1` step whenever a required field is added or a
+/// field's meaning changes, so older config files keep loading.
+const CURRENT_CONFIG_VERSION: u32 = 3;
+
+/// Probes a raw TOML document for its declared `version` field only,
+/// without requiring any of the other fields to be present - so it can run
+/// before we know which versioned shape to deserialize the rest into.
+/// Missing `version` means the file predates the field, not that it's on
+/// the v1 schema - only an explicit `version = 1` is parsed as `ConfigV1`.
+#[derive(Debug, Deserialize)]
+struct VersionProbe {
+    version: Option<u32>,
+}
+
+// v1 schema: the original fields, before `timeout_seconds`/`admin_email`
+// were added.
+#[derive(Debug, Deserialize)]
+struct ConfigV1 {
+    server_name: String,
+    port: u16,
+    max_connections: u32,
+}
+
+// v2 schema: adds `timeout_seconds`/`admin_email`, before `strip_subaddress`
+// was added.
+#[derive(Debug, Deserialize)]
+struct ConfigV2 {
     server_name: String,
     port: u16,
     max_connections: u32,
@@ -43,12 +121,105 @@ struct Config {
     admin_email: Option<String>,
 }
 
+// v3 schema: the current shape `Config::new` validates.
+#[derive(Debug, Deserialize)]
+struct ConfigV3 {
+    server_name: String,
+    port: u16,
+    max_connections: u32,
+    timeout_seconds: Option<u32>,
+    admin_email: Option<String>,
+    #[serde(default)]
+    strip_subaddress: bool,
+}
+
+/// Migrates a v1 config to v2, defaulting the fields v1 didn't have.
+fn migrate_v1_to_v2(v1: ConfigV1) -> ConfigV2 {
+    ConfigV2 {
+        server_name: v1.server_name,
+        port: v1.port,
+        max_connections: v1.max_connections,
+        timeout_seconds: None,
+        admin_email: None,
+    }
+}
+
+/// Migrates a v2 config to v3, defaulting `strip_subaddress` to off so
+/// existing `admin_email` values keep reading back unchanged.
+fn migrate_v2_to_v3(v2: ConfigV2) -> ConfigV3 {
+    ConfigV3 {
+        server_name: v2.server_name,
+        port: v2.port,
+        max_connections: v2.max_connections,
+        timeout_seconds: v2.timeout_seconds,
+        admin_email: v2.admin_email,
+        strip_subaddress: false,
+    }
+}
+
+// A numeric type `parse_bounded`/`check_bounded` can validate against a
+// declared range, reported back through `ConfigError::InvalidRange`'s `i32`
+// min/max/actual fields (inspired by hippotat's `#[limited]` capped
+// settings).
+trait BoundedValue: FromStr + PartialOrd + Copy {
+    fn to_range_i32(self) -> i32;
+}
+
+impl BoundedValue for u16 {
+    fn to_range_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+impl BoundedValue for u32 {
+    fn to_range_i32(self) -> i32 {
+        self as i32
+    }
+}
+
+// Validates that `value` falls within `range`, producing `InvalidRange` with
+// the field name filled in automatically when it doesn't.
+fn check_bounded<T: BoundedValue>(
+    field: &str,
+    value: T,
+    range: RangeInclusive<T>,
+) -> Result<T, ConfigError> {
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ConfigError::InvalidRange {
+            field: field.to_string(),
+            min: range.start().to_range_i32(),
+            max: range.end().to_range_i32(),
+            actual: value.to_range_i32(),
+        })
+    }
+}
+
+// Parses `value` as `T` and validates it against `range` in one step, so a
+// new bounded field only needs a one-line call instead of a copy-pasted
+// parse-then-check block.
+fn parse_bounded<T>(field: &str, value: &str, range: RangeInclusive<T>) -> Result<T, ConfigError>
+where
+    T: BoundedValue,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let parsed = value.parse::<T>().map_err(|e| ConfigError::ParseError {
+        field: field.to_string(),
+        message: e.to_string(),
+        source: Some(Box::new(e)),
+    })?;
+
+    check_bounded(field, parsed, range)
+}
+
 // Parse port from string with validation
 fn parse_port(value: &str) -> Result<u16, ConfigError> {
     // Parse string to u16
     let port = value.parse::<u16>().map_err(|e| ConfigError::ParseError {
         field: "port".to_string(),
         message: e.to_string(),
+        source: Some(Box::new(e)),
     })?;
 
     // Validate port is not zero
@@ -64,61 +235,76 @@ fn parse_port(value: &str) -> Result<u16, ConfigError> {
 
 // Parse max_connections from string with range validation
 fn parse_max_connections(value: &str) -> Result<u32, ConfigError> {
-    // Parse string to u32
-    let max_conn = value.parse::<u32>().map_err(|e| ConfigError::ParseError {
-        field: "max_connections".to_string(),
-        message: e.to_string(),
-    })?;
-
-    // Validate range (1 to 10000)
-    if max_conn < 1 || max_conn > 10000 {
-        return Err(ConfigError::InvalidRange {
-            field: "max_connections".to_string(),
-            min: 1,
-            max: 10000,
-            actual: max_conn as i32,
-        });
-    }
-
-    Ok(max_conn)
+    parse_bounded("max_connections", value, 1..=10000)
 }
 
-// Parse optional timeout with validation
+// Parse optional timeout with range validation
 fn parse_timeout(value: Option<&str>) -> Result<Option<u32>, ConfigError> {
-    // If None, return Ok(None)
     match value {
         None => Ok(None),
-        Some(s) => {
-            // Parse string to u32
-            let timeout = s.parse::<u32>().map_err(|e| ConfigError::ParseError {
-                field: "timeout_seconds".to_string(),
-                message: e.to_string(),
-            })?;
-
-            // Validate range (1 to 3600)
-            if timeout < 1 || timeout > 3600 {
-                return Err(ConfigError::InvalidRange {
-                    field: "timeout_seconds".to_string(),
-                    min: 1,
-                    max: 3600,
-                    actual: timeout as i32,
-                });
-            }
-
-            Ok(Some(timeout))
-        }
+        Some(s) => parse_bounded("timeout_seconds", s, 1..=3600).map(Some),
     }
 }
 
-// Validate email format (simple check for @ and .)
-fn validate_email(email: &str) -> Result<(), ConfigError> {
-    if !email.contains('@') || !email.contains('.') {
+// Lazily-compiled local-part/domain validation regexes, shared across calls.
+fn email_part_regexes() -> &'static (Regex, Regex) {
+    static PATTERNS: OnceLock<(Regex, Regex)> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        (
+            Regex::new(r"^[A-Za-z0-9](?:[A-Za-z0-9._%+-]*[A-Za-z0-9])?$").unwrap(),
+            Regex::new(r"^(?:[A-Za-z0-9](?:[A-Za-z0-9-]*[A-Za-z0-9])?\.)+[A-Za-z]{2,}$").unwrap(),
+        )
+    })
+}
+
+// Validates `email`'s local and domain parts against stricter regexes than
+// the old "contains '@' and '.'" check, then - when `strip_subaddress` is
+// set - normalizes a `user+tag@domain` subaddress down to `user@domain`
+// (the same rewrite mail servers apply before routing). Returns the
+// (possibly normalized) address to store.
+fn validate_email(email: &str, strip_subaddress: bool) -> Result<String, ConfigError> {
+    let (local, domain) = email.split_once('@').ok_or_else(|| ConfigError::ValidationError {
+        field: "admin_email".to_string(),
+        message: "local part is missing: no '@' separator found".to_string(),
+    })?;
+
+    let (local_part_re, domain_re) = email_part_regexes();
+
+    if !local_part_re.is_match(local) {
         return Err(ConfigError::ValidationError {
             field: "admin_email".to_string(),
-            message: "Email must contain '@' and '.'".to_string(),
+            message: format!("local part '{}' is not a valid email local part", local),
         });
     }
-    Ok(())
+
+    if !domain_re.is_match(domain) {
+        return Err(ConfigError::ValidationError {
+            field: "admin_email".to_string(),
+            message: format!("domain '{}' is not a valid email domain", domain),
+        });
+    }
+
+    let normalized_local = if strip_subaddress {
+        local.split_once('+').map_or(local, |(base, _tag)| base)
+    } else {
+        local
+    };
+
+    Ok(format!("{}@{}", normalized_local, domain))
+}
+
+// Pulls the backtick-quoted field name out of a toml parse error's message
+// (e.g. "missing field `port`"), falling back to a generic label when the
+// message doesn't name one.
+fn toml_error_field(error: &toml::de::Error) -> String {
+    let message = error.message();
+    message
+        .find('`')
+        .and_then(|start| {
+            let rest = &message[start + 1..];
+            rest.find('`').map(|end| rest[..end].to_string())
+        })
+        .unwrap_or_else(|| "config".to_string())
 }
 
 impl Config {
@@ -129,6 +315,7 @@ impl Config {
         max_connections: u32,
         timeout_seconds: Option<u32>,
         admin_email: Option<String>,
+        strip_subaddress: bool,
     ) -> Result<Config, ConfigError> {
         // Validate server_name is not empty
         if server_name.is_empty() {
@@ -146,41 +333,86 @@ impl Config {
         }
 
         // Validate max_connections range
-        if max_connections < 1 || max_connections > 10000 {
-            return Err(ConfigError::InvalidRange {
-                field: "max_connections".to_string(),
-                min: 1,
-                max: 10000,
-                actual: max_connections as i32,
-            });
-        }
+        let max_connections = check_bounded("max_connections", max_connections, 1..=10000)?;
 
         // Validate timeout_seconds if present
         if let Some(timeout) = timeout_seconds {
-            if timeout < 1 || timeout > 3600 {
-                return Err(ConfigError::InvalidRange {
-                    field: "timeout_seconds".to_string(),
-                    min: 1,
-                    max: 3600,
-                    actual: timeout as i32,
-                });
-            }
+            check_bounded("timeout_seconds", timeout, 1..=3600)?;
         }
 
-        // Validate admin_email if present
-        if let Some(ref email) = admin_email {
-            validate_email(email)?;
-        }
+        // Validate admin_email if present, normalizing its subaddress away
+        // when requested.
+        let admin_email = admin_email
+            .map(|email| validate_email(&email, strip_subaddress))
+            .transpose()?;
 
         Ok(Config {
+            version: CURRENT_CONFIG_VERSION,
             server_name,
             port,
             max_connections,
             timeout_seconds,
             admin_email,
+            strip_subaddress,
         })
     }
 
+    // Create Config from a TOML file: detect its declared schema version,
+    // migrate it up to the current one, then run the same validation as
+    // `new`.
+    fn from_file(path: &str) -> Result<Config, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|e| ConfigError::ParseError {
+            field: "file".to_string(),
+            message: e.to_string(),
+            source: Some(Box::new(e)),
+        })?;
+
+        let to_parse_error = |e: toml::de::Error| ConfigError::ParseError {
+            field: toml_error_field(&e),
+            message: e.message().to_string(),
+            source: Some(Box::new(e)),
+        };
+
+        let probe: VersionProbe = toml::from_str(&contents).map_err(to_parse_error)?;
+
+        if let Some(found) = probe.version {
+            if found > CURRENT_CONFIG_VERSION {
+                return Err(ConfigError::UnsupportedVersion {
+                    found,
+                    max_supported: CURRENT_CONFIG_VERSION,
+                });
+            }
+        }
+
+        // A missing `version` only means the file predates the field, not
+        // that it's stuck on the v1 field set - v2/v3 fields were added
+        // before `version` was, so an unversioned file may carry any of
+        // them. Parsing it against the latest schema keeps those fields
+        // instead of silently dropping them through `ConfigV1`. Only a file
+        // that explicitly declares `version = 1` gets migrated from the
+        // genuinely field-limited v1 shape.
+        let v3 = match probe.version {
+            Some(1) => {
+                let v1: ConfigV1 = toml::from_str(&contents).map_err(to_parse_error)?;
+                migrate_v2_to_v3(migrate_v1_to_v2(v1))
+            }
+            Some(2) => {
+                let v2: ConfigV2 = toml::from_str(&contents).map_err(to_parse_error)?;
+                migrate_v2_to_v3(v2)
+            }
+            None | Some(_) => toml::from_str::<ConfigV3>(&contents).map_err(to_parse_error)?,
+        };
+
+        Config::new(
+            v3.server_name,
+            v3.port,
+            v3.max_connections,
+            v3.timeout_seconds,
+            v3.admin_email,
+            v3.strip_subaddress,
+        )
+    }
+
     // Create Config from string values with error propagation
     fn from_strings(
         server_name: &str,
@@ -188,6 +420,7 @@ impl Config {
         max_conn_str: &str,
         timeout_str: Option<&str>,
         email: Option<&str>,
+        strip_subaddress: bool,
     ) -> Result<Config, ConfigError> {
         // Parse values using ? operator for error propagation
         let port = parse_port(port_str)?;
@@ -202,11 +435,13 @@ impl Config {
             max_connections,
             timeout_seconds,
             admin_email,
+            strip_subaddress,
         )
     }
 
     // Helper function to display config
     fn display(&self) {
+        println!("  Schema Version: {}", self.version);
         println!("  Server: {}", self.server_name);
         println!("  Port: {}", self.port);
         println!("  Max Connections: {}", self.max_connections);
@@ -217,7 +452,10 @@ impl Config {
         }
 
         match &self.admin_email {
-            Some(email) => println!("  Admin Email: {}", email),
+            Some(email) => println!(
+                "  Admin Email: {} (strip_subaddress: {})",
+                email, self.strip_subaddress
+            ),
             None => println!("  Admin Email: Not set"),
         }
     }
@@ -234,6 +472,7 @@ fn main() {
         "1000",
         Some("30"),
         Some("admin@example.com"),
+        false,
     ) {
         Ok(config) => {
             println!("✓ Config created successfully:");
@@ -244,7 +483,7 @@ fn main() {
 
     // Test 2: Invalid port (not a number)
     println!("\nTest 2: Invalid port (not a number)");
-    match Config::from_strings("server", "abc", "100", None, None) {
+    match Config::from_strings("server", "abc", "100", None, None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -254,7 +493,7 @@ fn main() {
 
     // Test 3: Invalid port (zero)
     println!("\nTest 3: Invalid port (zero)");
-    match Config::from_strings("server", "0", "100", None, None) {
+    match Config::from_strings("server", "0", "100", None, None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -264,7 +503,7 @@ fn main() {
 
     // Test 4: Invalid max_connections (out of range)
     println!("\nTest 4: Invalid max_connections (out of range)");
-    match Config::from_strings("server", "8080", "50000", None, None) {
+    match Config::from_strings("server", "8080", "50000", None, None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -274,7 +513,7 @@ fn main() {
 
     // Test 5: Invalid timeout (out of range)
     println!("\nTest 5: Invalid timeout (out of range)");
-    match Config::from_strings("server", "8080", "100", Some("7200"), None) {
+    match Config::from_strings("server", "8080", "100", Some("7200"), None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -284,7 +523,34 @@ fn main() {
 
     // Test 6: Invalid email format
     println!("\nTest 6: Invalid email format");
-    match Config::from_strings("server", "8080", "100", None, Some("invalid-email")) {
+    match Config::from_strings("server", "8080", "100", None, Some("invalid-email"), false) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+
+    // Test 7: Stripping a `+tag` subaddress down to its base address
+    println!("\nTest 7: Subaddress normalization");
+    match Config::from_strings(
+        "server",
+        "8080",
+        "100",
+        None,
+        Some("admin+newsletter@example.com"),
+        true,
+    ) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+
+    // Test 8: Valid configuration with optional fields as None
+    println!("\nTest 8: Valid configuration with optional fields as None");
+    match Config::from_strings("dev-server", "3000", "100", None, None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -292,9 +558,9 @@ fn main() {
         Err(e) => println!("✗ Error: {}", e),
     }
 
-    // Test 7: Valid configuration with optional fields as None
-    println!("\nTest 7: Valid configuration with optional fields as None");
-    match Config::from_strings("dev-server", "3000", "100", None, None) {
+    // Test 9: Missing server name (empty string)
+    println!("\nTest 9: Missing server name");
+    match Config::from_strings("", "8080", "100", None, None, false) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
@@ -302,15 +568,149 @@ fn main() {
         Err(e) => println!("✗ Error: {}", e),
     }
 
-    // Test 8: Missing server name (empty string)
-    println!("\nTest 8: Missing server name");
-    match Config::from_strings("", "8080", "100", None, None) {
+    // Test 10: Valid configuration loaded from a TOML file
+    println!("\nTest 10: Valid configuration from a TOML file");
+    let valid_toml_path = std::env::temp_dir().join("config-practice-valid.toml");
+    fs::write(
+        &valid_toml_path,
+        "server_name = \"toml-server\"\nport = 8080\nmax_connections = 500\ntimeout_seconds = 60\nadmin_email = \"admin@example.com\"\n",
+    )
+    .expect("failed to write demo TOML file");
+    match Config::from_file(valid_toml_path.to_str().unwrap()) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+    let _ = fs::remove_file(&valid_toml_path);
+
+    // Test 11: TOML file missing a required field
+    println!("\nTest 11: TOML file missing a required field");
+    let missing_field_toml_path = std::env::temp_dir().join("config-practice-missing-field.toml");
+    fs::write(&missing_field_toml_path, "port = 8080\nmax_connections = 500\n")
+        .expect("failed to write demo TOML file");
+    match Config::from_file(missing_field_toml_path.to_str().unwrap()) {
         Ok(config) => {
             println!("✓ Config created successfully:");
             config.display();
         }
         Err(e) => println!("✗ Error: {}", e),
     }
+    let _ = fs::remove_file(&missing_field_toml_path);
+
+    // Test 12: An unversioned TOML file parses directly against the current schema
+    println!("\nTest 12: Unversioned TOML file parses against the current schema");
+    let v1_toml_path = std::env::temp_dir().join("config-practice-v1.toml");
+    fs::write(
+        &v1_toml_path,
+        "server_name = \"legacy-server\"\nport = 8080\nmax_connections = 250\n",
+    )
+    .expect("failed to write demo TOML file");
+    match Config::from_file(v1_toml_path.to_str().unwrap()) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+    let _ = fs::remove_file(&v1_toml_path);
+
+    // Test 13: A v3 TOML file with strip_subaddress set normalizes admin_email
+    println!("\nTest 13: v3 TOML file with strip_subaddress normalizes admin_email");
+    let v3_toml_path = std::env::temp_dir().join("config-practice-v3.toml");
+    fs::write(
+        &v3_toml_path,
+        "version = 3\nserver_name = \"toml-server\"\nport = 8080\nmax_connections = 500\nadmin_email = \"admin+alerts@example.com\"\nstrip_subaddress = true\n",
+    )
+    .expect("failed to write demo TOML file");
+    match Config::from_file(v3_toml_path.to_str().unwrap()) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+    let _ = fs::remove_file(&v3_toml_path);
+
+    // Test 14: A TOML file declaring an unsupported future schema version
+    println!("\nTest 14: TOML file with an unsupported schema version");
+    let future_toml_path = std::env::temp_dir().join("config-practice-future.toml");
+    fs::write(
+        &future_toml_path,
+        "version = 99\nserver_name = \"future-server\"\nport = 8080\nmax_connections = 250\n",
+    )
+    .expect("failed to write demo TOML file");
+    match Config::from_file(future_toml_path.to_str().unwrap()) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+    let _ = fs::remove_file(&future_toml_path);
+
+    // Test 15: A TOML file explicitly declaring `version = 1` migrates through v1/v2
+    println!("\nTest 15: Explicit v1 TOML file migrates to the current schema");
+    let explicit_v1_toml_path = std::env::temp_dir().join("config-practice-explicit-v1.toml");
+    fs::write(
+        &explicit_v1_toml_path,
+        "version = 1\nserver_name = \"legacy-server\"\nport = 8080\nmax_connections = 250\n",
+    )
+    .expect("failed to write demo TOML file");
+    match Config::from_file(explicit_v1_toml_path.to_str().unwrap()) {
+        Ok(config) => {
+            println!("✓ Config created successfully:");
+            config.display();
+        }
+        Err(e) => println!("✗ Error: {}", e),
+    }
+    let _ = fs::remove_file(&explicit_v1_toml_path);
+
+    // Test 16: Hot-reloading a config file via ConfigWatcher
+    println!("\nTest 16: Hot-reloading a config file via ConfigWatcher");
+    let watched_path = std::env::temp_dir().join("config-practice-watched.toml");
+    fs::write(
+        &watched_path,
+        "server_name = \"watched-server\"\nport = 9000\nmax_connections = 100\n",
+    )
+    .expect("failed to write demo TOML file");
+
+    match ConfigWatcher::new(&watched_path) {
+        Ok(watcher) => {
+            println!("✓ Watcher started, initial config:");
+            watcher.current().display();
+
+            let reloads = watcher.subscribe();
+
+            // Rewrite the file with a new port; editors often emit several
+            // write events for one save, which the watcher should debounce
+            // into a single reload.
+            fs::write(
+                &watched_path,
+                "server_name = \"watched-server\"\nport = 9100\nmax_connections = 100\n",
+            )
+            .expect("failed to rewrite demo TOML file");
+
+            match reloads.recv_timeout(Duration::from_secs(2)) {
+                Ok(reloaded) => {
+                    println!("✓ Reload observed:");
+                    reloaded.display();
+                }
+                Err(_) => println!("✗ No reload observed within timeout"),
+            }
+
+            // An invalid rewrite should be rejected, leaving the last-good
+            // config in place.
+            fs::write(&watched_path, "server_name = \"watched-server\"\nport = 0\n")
+                .expect("failed to rewrite demo TOML file");
+            thread::sleep(Duration::from_millis(500));
+            println!("✓ After invalid rewrite, current config is still:");
+            watcher.current().display();
+        }
+        Err(e) => println!("✗ Error starting watcher: {}", e),
+    }
+    let _ = fs::remove_file(&watched_path);
 
     println!("\n=== All tests completed ===");
 }
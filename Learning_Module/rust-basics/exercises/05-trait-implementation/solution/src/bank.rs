@@ -0,0 +1,54 @@
+// A simple coin bank for the inventory demo: gives `value()`/`stack_value()`
+// an actual sink instead of just being printed.
+
+use std::fmt;
+
+pub const MAX_BALANCE: u32 = 999_999;
+
+#[derive(Debug)]
+pub enum BankError {
+    Full,
+    Insufficient(u32),
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BankError::Full => write!(f, "Bank is full (cap: {} coins)", MAX_BALANCE),
+            BankError::Insufficient(balance) => {
+                write!(f, "Insufficient balance ({} coins)", balance)
+            }
+        }
+    }
+}
+
+pub struct Bank {
+    coins: u32,
+}
+
+impl Bank {
+    pub fn new() -> Self {
+        Bank { coins: 0 }
+    }
+
+    pub fn balance(&self) -> u32 {
+        self.coins
+    }
+
+    pub fn add_coins(&mut self, amount: u32) -> Result<(), BankError> {
+        let new_balance = self.coins.checked_add(amount).ok_or(BankError::Full)?;
+        if new_balance > MAX_BALANCE {
+            return Err(BankError::Full);
+        }
+        self.coins = new_balance;
+        Ok(())
+    }
+
+    pub fn remove_coins(&mut self, amount: u32) -> Result<(), BankError> {
+        if amount > self.coins {
+            return Err(BankError::Insufficient(self.coins));
+        }
+        self.coins -= amount;
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+// Crafting subsystem: turns `Material` stacks into finished `Weapon`/`Potion`
+// items, consuming the materials they require.
+
+use crate::{Inventory, Item, Material, Potion, Weapon};
+
+/// What kind of finished item a recipe produces, along with the stats that
+/// aren't derived from the consumed materials.
+pub enum CraftKind {
+    Weapon { damage: u32, weight: f32 },
+    Potion { healing: u32, quantity: u32 },
+}
+
+pub struct Recipe {
+    pub output_name: String,
+    pub inputs: Vec<(String, u32)>,
+    pub requires_tool: bool,
+    pub kind: CraftKind,
+    pub value: u32,
+}
+
+/// Penalty applied to the output value when a recipe is improvised without
+/// its tool, mirroring the external MUD's "improvise" mode.
+const IMPROVISE_VALUE_PENALTY: f32 = 0.5;
+
+/// Crafts `recipe` from the materials in `inv`. Fails without consuming
+/// anything if a required tool is missing or an input material is short;
+/// otherwise decrements the consumed materials and returns the finished item.
+/// Recipes that don't strictly require a tool can still be improvised
+/// without one, at a reduced output value.
+pub fn craft(
+    inv: &mut Inventory<Material>,
+    recipe: &Recipe,
+    has_tool: bool,
+) -> Result<Box<dyn Item>, String> {
+    if recipe.requires_tool && !has_tool {
+        return Err(format!("{} requires a tool", recipe.output_name));
+    }
+
+    for (name, needed) in &recipe.inputs {
+        let available = inv.find_by_name(name).map(|m| m.quantity).unwrap_or(0);
+        if available < *needed {
+            return Err(format!(
+                "Not enough {} (need {}, have {})",
+                name, needed, available
+            ));
+        }
+    }
+
+    for (name, needed) in &recipe.inputs {
+        consume_material(inv, name, *needed);
+    }
+
+    let value = if has_tool {
+        recipe.value
+    } else {
+        (recipe.value as f32 * IMPROVISE_VALUE_PENALTY).round() as u32
+    };
+
+    let item: Box<dyn Item> = match recipe.kind {
+        CraftKind::Weapon { damage, weight } => Box::new(Weapon {
+            name: recipe.output_name.clone(),
+            damage,
+            value,
+            weight,
+            grind: 0,
+            special: None,
+            attributes: [None, None, None],
+        }),
+        CraftKind::Potion { healing, quantity } => Box::new(Potion {
+            name: recipe.output_name.clone(),
+            healing,
+            value,
+            quantity,
+            freshness: crate::POTION_MAX_FRESHNESS,
+        }),
+    };
+
+    Ok(item)
+}
+
+fn consume_material(inv: &mut Inventory<Material>, name: &str, quantity: u32) {
+    if let Some(pos) = inv.items.iter().position(|m| m.name() == name) {
+        let remaining = inv.items[pos].quantity.saturating_sub(quantity);
+        if remaining == 0 {
+            inv.items.remove(pos);
+        } else {
+            inv.items[pos].quantity = remaining;
+        }
+    }
+}
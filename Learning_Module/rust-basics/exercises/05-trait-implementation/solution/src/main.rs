@@ -1,5 +1,15 @@
+use std::collections::HashMap;
 use std::fmt;
 
+mod bank;
+mod crafting;
+mod pluralize;
+mod shop;
+use bank::Bank;
+use crafting::{craft, CraftKind, Recipe};
+use pluralize::pluralize;
+use shop::{generate_shop, Element, Lcg, Special};
+
 // Define the Item trait
 trait Item {
     fn name(&self) -> &str;
@@ -12,12 +22,60 @@ trait Item {
 trait Stackable {
     fn max_stack_size(&self) -> u32;
     fn stack_value(&self, quantity: u32) -> u32;
+    fn quantity(&self) -> u32;
+    fn set_quantity(&mut self, quantity: u32);
 }
 
 // Define the Serializable trait
 trait Serializable {
     fn serialize(&self) -> String;
     fn type_name(&self) -> &str;
+    fn deserialize(data: &str) -> Result<Self, String>
+    where
+        Self: Sized;
+}
+
+/// Splits a `TypeName{k:v,k:v}` envelope into its fields, keyed by field
+/// name, after checking the envelope's type name matches `expected_type`.
+fn parse_envelope<'a>(
+    data: &'a str,
+    expected_type: &str,
+) -> Result<HashMap<&'a str, &'a str>, String> {
+    let body = data
+        .strip_prefix(expected_type)
+        .and_then(|rest| rest.strip_prefix('{'))
+        .and_then(|rest| rest.strip_suffix('}'))
+        .ok_or_else(|| format!("Expected {} envelope, got: {}", expected_type, data))?;
+
+    body.split(',')
+        .map(|pair| {
+            pair.split_once(':')
+                .ok_or_else(|| format!("Malformed field: {}", pair))
+        })
+        .collect()
+}
+
+/// Reads the leading type name off a serialized item and routes it to the
+/// matching `deserialize`, so a file of mixed item lines can be reloaded
+/// without the caller knowing each line's concrete type up front.
+fn deserialize_any(data: &str) -> Result<Box<dyn Item>, String> {
+    let type_name = data
+        .split_once('{')
+        .map(|(name, _)| name)
+        .ok_or_else(|| format!("Malformed item data: {}", data))?;
+
+    match type_name {
+        "Weapon" => Ok(Box::new(Weapon::deserialize(data)?)),
+        "Potion" => Ok(Box::new(Potion::deserialize(data)?)),
+        "Material" => Ok(Box::new(Material::deserialize(data)?)),
+        other => Err(format!("Unknown item type: {}", other)),
+    }
+}
+
+// Define the Perishable trait
+trait Perishable {
+    fn tick(&mut self);
+    fn is_spoiled(&self) -> bool;
 }
 
 // Weapon struct
@@ -27,8 +85,16 @@ struct Weapon {
     damage: u32,
     value: u32,
     weight: f32,
+    grind: u8,
+    special: Option<Special>,
+    attributes: [Option<(Element, i8)>; 3],
 }
 
+// Ticks of freshness a freshly-made potion starts with before it spoils.
+const POTION_MAX_FRESHNESS: u32 = 3;
+const SPOILED_HEALING: u32 = 0;
+const SPOILED_VALUE: u32 = 0;
+
 // Potion struct
 #[derive(Debug, Clone)]
 struct Potion {
@@ -36,6 +102,7 @@ struct Potion {
     healing: u32,
     value: u32,
     quantity: u32,
+    freshness: u32,
 }
 
 // Material struct
@@ -105,7 +172,8 @@ impl Item for Material {
     fn description(&self) -> String {
         format!(
             "Crafting material: {} (Quantity: {})",
-            self.name, self.quantity
+            pluralize(&self.name, self.quantity),
+            self.quantity
         )
     }
 }
@@ -119,6 +187,32 @@ impl Stackable for Potion {
     fn stack_value(&self, quantity: u32) -> u32 {
         self.value * quantity
     }
+
+    fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    fn set_quantity(&mut self, quantity: u32) {
+        self.quantity = quantity;
+    }
+}
+
+// Implement Perishable trait for Potion
+impl Perishable for Potion {
+    fn tick(&mut self) {
+        if self.freshness == 0 {
+            return;
+        }
+        self.freshness -= 1;
+        if self.freshness == 0 {
+            self.healing = SPOILED_HEALING;
+            self.value = SPOILED_VALUE;
+        }
+    }
+
+    fn is_spoiled(&self) -> bool {
+        self.freshness == 0
+    }
 }
 
 // Implement Stackable trait for Material
@@ -130,15 +224,33 @@ impl Stackable for Material {
     fn stack_value(&self, quantity: u32) -> u32 {
         self.value * quantity
     }
+
+    fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    fn set_quantity(&mut self, quantity: u32) {
+        self.quantity = quantity;
+    }
 }
 
 // Implement Display trait for Weapon
 impl fmt::Display for Weapon {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let special = match &self.special {
+            Some(special) => format!(", Special: {}", special.name()),
+            None => String::new(),
+        };
         write!(
             f,
-            "{} (Damage: {}, Value: {} coins, Weight: {} kg)",
-            self.name, self.damage, self.value, self.weight
+            "{} (Damage: {}, Value: {} coins, Weight: {} kg, Grind: +{}{}{})",
+            self.name,
+            self.damage,
+            self.value,
+            self.weight,
+            self.grind,
+            special,
+            shop::attributes_display(&self.attributes)
         )
     }
 }
@@ -149,7 +261,10 @@ impl fmt::Display for Potion {
         write!(
             f,
             "{} (Healing: {}, Value: {} coins, Quantity: {})",
-            self.name, self.healing, self.value, self.quantity
+            pluralize(&self.name, self.quantity),
+            self.healing,
+            self.value,
+            self.quantity
         )
     }
 }
@@ -160,7 +275,10 @@ impl fmt::Display for Material {
         write!(
             f,
             "{} (Value: {} coins, Weight: {} kg, Quantity: {})",
-            self.name, self.value, self.weight, self.quantity
+            pluralize(&self.name, self.quantity),
+            self.value,
+            self.weight,
+            self.quantity
         )
     }
 }
@@ -169,28 +287,100 @@ impl fmt::Display for Material {
 impl Serializable for Weapon {
     fn serialize(&self) -> String {
         format!(
-            "Weapon{{name:{},damage:{},value:{},weight:{}}}",
-            self.name, self.damage, self.value, self.weight
+            "Weapon{{name:{},damage:{},value:{},weight:{},grind:{},special:{},attributes:{}}}",
+            self.name,
+            self.damage,
+            self.value,
+            self.weight,
+            self.grind,
+            self.special.map(|s| s.name()).unwrap_or("None"),
+            shop::serialize_attributes(&self.attributes)
         )
     }
 
     fn type_name(&self) -> &str {
         "Weapon"
     }
+
+    fn deserialize(data: &str) -> Result<Self, String> {
+        let fields = parse_envelope(data, "Weapon")?;
+        let grind = match fields.get("grind") {
+            Some(value) => value.parse().map_err(|_| "Invalid grind".to_string())?,
+            None => 0,
+        };
+        let special = match fields.get("special") {
+            None | Some(&"None") => None,
+            Some(name) => {
+                Some(Special::from_name(name).ok_or_else(|| format!("Invalid special: {}", name))?)
+            }
+        };
+        let attributes = match fields.get("attributes") {
+            Some(value) => shop::parse_attributes(value)?,
+            None => [None, None, None],
+        };
+
+        Ok(Weapon {
+            name: (*fields.get("name").ok_or("Missing name")?).to_string(),
+            damage: fields
+                .get("damage")
+                .ok_or("Missing damage")?
+                .parse()
+                .map_err(|_| "Invalid damage".to_string())?,
+            value: fields
+                .get("value")
+                .ok_or("Missing value")?
+                .parse()
+                .map_err(|_| "Invalid value".to_string())?,
+            weight: fields
+                .get("weight")
+                .ok_or("Missing weight")?
+                .parse()
+                .map_err(|_| "Invalid weight".to_string())?,
+            grind,
+            special,
+            attributes,
+        })
+    }
 }
 
 // Implement Serializable trait for Potion
 impl Serializable for Potion {
     fn serialize(&self) -> String {
         format!(
-            "Potion{{name:{},healing:{},value:{},quantity:{}}}",
-            self.name, self.healing, self.value, self.quantity
+            "Potion{{name:{},healing:{},value:{},quantity:{},freshness:{}}}",
+            self.name, self.healing, self.value, self.quantity, self.freshness
         )
     }
 
     fn type_name(&self) -> &str {
         "Potion"
     }
+
+    fn deserialize(data: &str) -> Result<Self, String> {
+        let fields = parse_envelope(data, "Potion")?;
+        Ok(Potion {
+            name: (*fields.get("name").ok_or("Missing name")?).to_string(),
+            healing: fields
+                .get("healing")
+                .ok_or("Missing healing")?
+                .parse()
+                .map_err(|_| "Invalid healing".to_string())?,
+            value: fields
+                .get("value")
+                .ok_or("Missing value")?
+                .parse()
+                .map_err(|_| "Invalid value".to_string())?,
+            quantity: fields
+                .get("quantity")
+                .ok_or("Missing quantity")?
+                .parse()
+                .map_err(|_| "Invalid quantity".to_string())?,
+            freshness: match fields.get("freshness") {
+                Some(value) => value.parse().map_err(|_| "Invalid freshness".to_string())?,
+                None => POTION_MAX_FRESHNESS,
+            },
+        })
+    }
 }
 
 // Implement Serializable trait for Material
@@ -205,6 +395,28 @@ impl Serializable for Material {
     fn type_name(&self) -> &str {
         "Material"
     }
+
+    fn deserialize(data: &str) -> Result<Self, String> {
+        let fields = parse_envelope(data, "Material")?;
+        Ok(Material {
+            name: (*fields.get("name").ok_or("Missing name")?).to_string(),
+            value: fields
+                .get("value")
+                .ok_or("Missing value")?
+                .parse()
+                .map_err(|_| "Invalid value".to_string())?,
+            weight: fields
+                .get("weight")
+                .ok_or("Missing weight")?
+                .parse()
+                .map_err(|_| "Invalid weight".to_string())?,
+            quantity: fields
+                .get("quantity")
+                .ok_or("Missing quantity")?
+                .parse()
+                .map_err(|_| "Invalid quantity".to_string())?,
+        })
+    }
 }
 
 // Generic Inventory struct
@@ -251,6 +463,72 @@ impl<T: Item + Clone> Inventory<T> {
     }
 }
 
+// Additional methods for inventories of stackable items: merges into
+// existing stacks before creating new ones, instead of always pushing a
+// fresh entry.
+impl<T: Item + Stackable + Clone> Inventory<T> {
+    fn add_stackable(&mut self, mut item: T) -> Result<(), String> {
+        let max_stack = item.max_stack_size();
+        let mut merged = self.items.clone();
+
+        for existing in merged.iter_mut() {
+            if existing.name() != item.name() {
+                continue;
+            }
+            let room = max_stack.saturating_sub(existing.quantity());
+            if room == 0 {
+                continue;
+            }
+            let moved = room.min(item.quantity());
+            existing.set_quantity(existing.quantity() + moved);
+            item.set_quantity(item.quantity() - moved);
+            if item.quantity() == 0 {
+                break;
+            }
+        }
+
+        while item.quantity() > 0 {
+            let take = item.quantity().min(max_stack);
+            let mut stack = item.clone();
+            stack.set_quantity(take);
+            merged.push(stack);
+            item.set_quantity(item.quantity() - take);
+        }
+
+        let new_weight: f32 = merged.iter().map(|i| i.weight()).sum();
+        if new_weight > self.max_weight {
+            return Err(format!(
+                "Exceeds maximum weight ({:.1}/{:.1} kg)",
+                new_weight, self.max_weight
+            ));
+        }
+
+        self.items = merged;
+        Ok(())
+    }
+}
+
+// Additional method for inventories of perishable items: advances every
+// item a tick and drops the ones that spoiled.
+impl<T: Item + Perishable + Clone> Inventory<T> {
+    fn tick_all(&mut self) -> Vec<String> {
+        for item in self.items.iter_mut() {
+            item.tick();
+        }
+
+        let mut removed = Vec::new();
+        self.items.retain(|item| {
+            if item.is_spoiled() {
+                removed.push(item.name().to_string());
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+}
+
 // Generic function to display item info
 fn display_item_info<T: Item>(item: &T) {
     println!("Item: {}", item.name());
@@ -276,6 +554,9 @@ fn main() {
         damage: 50,
         value: 100,
         weight: 5.0,
+        grind: 0,
+        special: None,
+        attributes: [None, None, None],
     };
 
     let axe = Weapon {
@@ -283,6 +564,9 @@ fn main() {
         damage: 60,
         value: 150,
         weight: 7.0,
+        grind: 0,
+        special: None,
+        attributes: [None, None, None],
     };
 
     let greatsword = Weapon {
@@ -290,6 +574,9 @@ fn main() {
         damage: 80,
         value: 200,
         weight: 10.0,
+        grind: 0,
+        special: None,
+        attributes: [None, None, None],
     };
 
     let health_potion = Potion {
@@ -297,6 +584,7 @@ fn main() {
         healing: 50,
         value: 25,
         quantity: 10,
+        freshness: POTION_MAX_FRESHNESS,
     };
 
     let mana_potion = Potion {
@@ -304,6 +592,7 @@ fn main() {
         healing: 30,
         value: 30,
         quantity: 5,
+        freshness: POTION_MAX_FRESHNESS,
     };
 
     let iron_ore = Material {
@@ -360,17 +649,25 @@ fn main() {
         println!("\nMost valuable weapon: {} ({} coins)", most_val.name(), most_val.value());
     }
 
+    // Weapon shop
+    println!("\n--- Weapon Shop (Tier 3) ---");
+    let mut rng = Lcg::new(42);
+    let shop_stock = generate_shop(5, 3, &mut rng);
+    for weapon in &shop_stock {
+        println!("  {}", weapon);
+    }
+
     // Potion inventory
     println!("\n--- Potion Inventory ---");
     let mut potion_inventory = Inventory::new(5.0);
 
-    println!("Adding {}x {}...", health_potion.quantity, health_potion.name());
+    println!("Adding {}x {}...", health_potion.quantity, pluralize(health_potion.name(), health_potion.quantity));
     match potion_inventory.add_item(health_potion.clone()) {
         Ok(_) => println!("✓ Item added successfully"),
         Err(e) => println!("✗ Cannot add item: {}", e),
     }
 
-    println!("Adding {}x {}...", mana_potion.quantity, mana_potion.name());
+    println!("Adding {}x {}...", mana_potion.quantity, pluralize(mana_potion.name(), mana_potion.quantity));
     match potion_inventory.add_item(mana_potion.clone()) {
         Ok(_) => println!("✓ Item added successfully"),
         Err(e) => println!("✗ Cannot add item: {}", e),
@@ -391,17 +688,36 @@ fn main() {
         health_potion.stack_value(health_potion.quantity)
     );
 
+    println!("\nAdding 95x {} (should merge into the existing stack and spill over)...", pluralize(health_potion.name(), 95));
+    let more_health_potions = Potion {
+        name: String::from("Health Potion"),
+        healing: 50,
+        value: 25,
+        quantity: 95,
+        freshness: POTION_MAX_FRESHNESS,
+    };
+    match potion_inventory.add_stackable(more_health_potions) {
+        Ok(_) => println!("✓ Item added successfully"),
+        Err(e) => println!("✗ Cannot add item: {}", e),
+    }
+    println!("  Items: {}", potion_inventory.count());
+    for (i, potion) in potion_inventory.items.iter().enumerate() {
+        if potion.name() == "Health Potion" {
+            println!("  Stack {}: {}x {}", i, potion.quantity, potion.name());
+        }
+    }
+
     // Material inventory
     println!("\n--- Material Inventory ---");
     let mut material_inventory = Inventory::new(200.0);
 
-    println!("Adding {}x {}...", iron_ore.quantity, iron_ore.name());
+    println!("Adding {}x {}...", iron_ore.quantity, pluralize(iron_ore.name(), iron_ore.quantity));
     match material_inventory.add_item(iron_ore.clone()) {
         Ok(_) => println!("✓ Item added successfully"),
         Err(e) => println!("✗ Cannot add item: {}", e),
     }
 
-    println!("Adding {}x {}...", wood.quantity, wood.name());
+    println!("Adding {}x {}...", wood.quantity, pluralize(wood.name(), wood.quantity));
     match material_inventory.add_item(wood.clone()) {
         Ok(_) => println!("✓ Item added successfully"),
         Err(e) => println!("✗ Cannot add item: {}", e),
@@ -416,6 +732,102 @@ fn main() {
     );
     println!("  Total Value: {} coins", material_inventory.total_value());
 
+    // Crafting
+    println!("\n--- Crafting ---");
+    let iron_sword_recipe = Recipe {
+        output_name: String::from("Iron Sword"),
+        inputs: vec![(String::from("Iron Ore"), 20), (String::from("Wood"), 5)],
+        requires_tool: true,
+        kind: CraftKind::Weapon {
+            damage: 40,
+            weight: 4.0,
+        },
+        value: 120,
+    };
+
+    println!("Crafting {} with a tool...", iron_sword_recipe.output_name);
+    match craft(&mut material_inventory, &iron_sword_recipe, true) {
+        Ok(item) => println!("✓ Crafted: {}", item.description()),
+        Err(e) => println!("✗ Cannot craft: {}", e),
+    }
+
+    let campfire_potion_recipe = Recipe {
+        output_name: String::from("Crude Tonic"),
+        inputs: vec![(String::from("Wood"), 10)],
+        requires_tool: false,
+        kind: CraftKind::Potion {
+            healing: 10,
+            quantity: 1,
+        },
+        value: 20,
+    };
+
+    println!(
+        "Improvising {} without a tool...",
+        campfire_potion_recipe.output_name
+    );
+    match craft(&mut material_inventory, &campfire_potion_recipe, false) {
+        Ok(item) => println!("✓ Crafted: {}", item.description()),
+        Err(e) => println!("✗ Cannot craft: {}", e),
+    }
+
+    println!(
+        "\nMaterial Inventory after crafting: {} items, {:.1} kg",
+        material_inventory.count(),
+        material_inventory.total_weight()
+    );
+
+    // Bank
+    println!("\n--- Bank ---");
+    let mut player_bank = Bank::new();
+
+    println!("Selling weapon inventory ({} coins)...", weapon_inventory.total_value());
+    match player_bank.add_coins(weapon_inventory.total_value()) {
+        Ok(_) => println!("✓ Deposited, balance: {} coins", player_bank.balance()),
+        Err(e) => println!("✗ Cannot deposit: {}", e),
+    }
+
+    println!(
+        "Selling {}x {}...",
+        health_potion.quantity,
+        pluralize(health_potion.name(), health_potion.quantity)
+    );
+    match player_bank.add_coins(health_potion.stack_value(health_potion.quantity)) {
+        Ok(_) => println!("✓ Deposited, balance: {} coins", player_bank.balance()),
+        Err(e) => println!("✗ Cannot deposit: {}", e),
+    }
+
+    let greatsword_price = greatsword.value();
+    println!("Buying {} for {} coins...", greatsword.name(), greatsword_price);
+    match player_bank.remove_coins(greatsword_price) {
+        Ok(_) => println!("✓ Withdrawn, balance: {} coins", player_bank.balance()),
+        Err(e) => println!("✗ Cannot withdraw: {}", e),
+    }
+
+    println!("Trying to withdraw 1,000,000 coins...");
+    match player_bank.remove_coins(1_000_000) {
+        Ok(_) => println!("✓ Withdrawn, balance: {} coins", player_bank.balance()),
+        Err(e) => println!("✗ Cannot withdraw: {}", e),
+    }
+
+    // Freshness ticks
+    println!("\n--- Freshness Ticks ---");
+    for tick_num in 1..=POTION_MAX_FRESHNESS + 1 {
+        let removed = potion_inventory.tick_all();
+        println!("Tick {}:", tick_num);
+        if removed.is_empty() {
+            println!("  Nothing spoiled");
+        } else {
+            for name in &removed {
+                println!("  {} spoiled and was discarded", name);
+            }
+        }
+    }
+    println!(
+        "Potions remaining after ticking: {}",
+        potion_inventory.count()
+    );
+
     // Display item details
     println!("\n--- Item Details ---");
     display_item_info(&sword);
@@ -424,9 +836,26 @@ fn main() {
 
     // Serialization
     println!("\n--- Serialization ---");
-    println!("Weapon: {}", sword.serialize());
-    println!("Potion: {}", health_potion.serialize());
-    println!("Material: {}", iron_ore.serialize());
+    let weapon_line = sword.serialize();
+    let potion_line = health_potion.serialize();
+    let material_line = iron_ore.serialize();
+    println!("Weapon: {}", weapon_line);
+    println!("Potion: {}", potion_line);
+    println!("Material: {}", material_line);
+
+    println!("\n--- Round-Trip Deserialization ---");
+    for line in [&weapon_line, &potion_line, &material_line] {
+        match deserialize_any(line) {
+            Ok(item) => println!("Reloaded: {}", item.description()),
+            Err(e) => println!("✗ Cannot reload '{}': {}", line, e),
+        }
+    }
+
+    println!("Parsing a malformed line...");
+    match deserialize_any("Weapon{name:Sword,damage:oops}") {
+        Ok(item) => println!("Reloaded: {}", item.description()),
+        Err(e) => println!("✗ Cannot reload: {}", e),
+    }
 
     // Finding items
     println!("\n--- Finding Items ---");
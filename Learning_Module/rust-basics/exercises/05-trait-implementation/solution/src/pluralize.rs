@@ -0,0 +1,60 @@
+// English pluralization for item display: an ordered irregular-suffix rule
+// table, falling back to the standard s/es rules when nothing matches.
+
+struct PluralRule {
+    match_suffix: &'static str,
+    drop: usize,
+    append_suffix: &'static str,
+}
+
+// Lazily-built irregular rule table, most specific suffix first.
+fn plural_rules() -> &'static Vec<PluralRule> {
+    static RULES: std::sync::OnceLock<Vec<PluralRule>> = std::sync::OnceLock::new();
+    RULES.get_or_init(|| {
+        vec![
+            PluralRule { match_suffix: "foot", drop: 3, append_suffix: "eet" },
+            PluralRule { match_suffix: "tooth", drop: 4, append_suffix: "eeth" },
+            PluralRule { match_suffix: "man", drop: 2, append_suffix: "en" },
+            PluralRule { match_suffix: "mouse", drop: 4, append_suffix: "ice" },
+            PluralRule { match_suffix: "louse", drop: 4, append_suffix: "ice" },
+            PluralRule { match_suffix: "fish", drop: 0, append_suffix: "" },
+            PluralRule { match_suffix: "sheep", drop: 0, append_suffix: "" },
+            PluralRule { match_suffix: "deer", drop: 0, append_suffix: "" },
+        ]
+    })
+}
+
+/// Pluralizes `name` for `quantity`, handling a trailing "<head> of
+/// <descriptor>" split so only the head noun changes (e.g. "pair of boots"
+/// -> "pairs of boots").
+pub fn pluralize(name: &str, quantity: u32) -> String {
+    if quantity == 1 {
+        return name.to_string();
+    }
+
+    match name.split_once(" of ") {
+        Some((head, tail)) => format!("{} of {}", pluralize_word(head), tail),
+        None => pluralize_word(name),
+    }
+}
+
+fn pluralize_word(word: &str) -> String {
+    let lower = word.to_lowercase();
+    for rule in plural_rules() {
+        if lower.ends_with(rule.match_suffix) {
+            let stem = &word[..word.len() - rule.drop];
+            return format!("{}{}", stem, rule.append_suffix);
+        }
+    }
+
+    if lower.ends_with('s')
+        || lower.ends_with('x')
+        || lower.ends_with('z')
+        || lower.ends_with("ch")
+        || lower.ends_with("sh")
+    {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
+    }
+}
@@ -0,0 +1,251 @@
+// Randomized weapon shop stock, drawing on the external PSO weapon-shop
+// design: a tier-gated pool of specials, grind, and up to three elemental
+// attribute percentages, all scaling the weapon's value/damage.
+
+use crate::Weapon;
+
+/// Minimal RNG interface so `generate_shop` doesn't depend on an external
+/// crate. `Lcg` below is a std-only implementation good enough for rolling
+/// shop stock.
+pub trait Rng {
+    fn next_u32(&mut self) -> u32;
+
+    /// A pseudo-random value in `0..bound`. `bound` must be greater than 0.
+    fn gen_range(&mut self, bound: u32) -> u32 {
+        self.next_u32() % bound
+    }
+}
+
+/// A linear congruential generator (Knuth's MMIX constants) - not
+/// suitable for anything security-sensitive, only for drawing shop stock.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg { state: seed }
+    }
+}
+
+impl Rng for Lcg {
+    fn next_u32(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 32) as u32
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Special {
+    Draw,
+    Heart,
+    Ice,
+    Heat,
+    Shock,
+    Drain,
+    Fire,
+    Thunder,
+}
+
+impl Special {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Special::Draw => "Draw",
+            Special::Heart => "Heart",
+            Special::Ice => "Ice",
+            Special::Heat => "Heat",
+            Special::Shock => "Shock",
+            Special::Drain => "Drain",
+            Special::Fire => "Fire",
+            Special::Thunder => "Thunder",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Special> {
+        match name {
+            "Draw" => Some(Special::Draw),
+            "Heart" => Some(Special::Heart),
+            "Ice" => Some(Special::Ice),
+            "Heat" => Some(Special::Heat),
+            "Shock" => Some(Special::Shock),
+            "Drain" => Some(Special::Drain),
+            "Fire" => Some(Special::Fire),
+            "Thunder" => Some(Special::Thunder),
+            _ => None,
+        }
+    }
+
+    /// Percentage bonus a weapon with this special adds to its shop value.
+    fn value_bonus_percent(&self) -> u32 {
+        match self {
+            Special::Draw | Special::Heart => 10,
+            Special::Ice | Special::Heat | Special::Shock => 20,
+            Special::Drain | Special::Fire | Special::Thunder => 35,
+        }
+    }
+}
+
+const WEAK_SPECIALS: [Special; 2] = [Special::Draw, Special::Heart];
+const MID_SPECIALS: [Special; 3] = [Special::Ice, Special::Heat, Special::Shock];
+const STRONG_SPECIALS: [Special; 3] = [Special::Drain, Special::Fire, Special::Thunder];
+
+/// The specials available at `tier` - higher tiers unlock additional,
+/// stronger specials on top of the weaker ones rather than replacing them.
+fn special_pool(tier: u8) -> Vec<Special> {
+    let mut pool = WEAK_SPECIALS.to_vec();
+    if tier >= 2 {
+        pool.extend_from_slice(&MID_SPECIALS);
+    }
+    if tier >= 4 {
+        pool.extend_from_slice(&STRONG_SPECIALS);
+    }
+    pool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Element {
+    Fire,
+    Ice,
+    Thunder,
+    Light,
+    Dark,
+}
+
+const ELEMENTS: [Element; 5] = [
+    Element::Fire,
+    Element::Ice,
+    Element::Thunder,
+    Element::Light,
+    Element::Dark,
+];
+
+impl Element {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Element::Fire => "Fire",
+            Element::Ice => "Ice",
+            Element::Thunder => "Thunder",
+            Element::Light => "Light",
+            Element::Dark => "Dark",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Element> {
+        match name {
+            "Fire" => Some(Element::Fire),
+            "Ice" => Some(Element::Ice),
+            "Thunder" => Some(Element::Thunder),
+            "Light" => Some(Element::Light),
+            "Dark" => Some(Element::Dark),
+            _ => None,
+        }
+    }
+}
+
+/// Base stock a shop can draw from before grind/special/attribute rolls.
+const BASE_WEAPONS: [(&str, u32, u32, f32); 3] = [
+    ("Saber", 20, 80, 6.0),
+    ("Sword", 35, 120, 9.0),
+    ("Dagger", 12, 60, 2.0),
+];
+
+/// Generates `count` randomized weapons for a shop of the given `tier`:
+/// rolls a base weapon, a grind level and attribute percentages within
+/// tier-scaled bounds, and a special from the tier-gated pool, then scales
+/// `value`/`damage` by grind and special.
+pub fn generate_shop(count: usize, tier: u8, rng: &mut impl Rng) -> Vec<Weapon> {
+    let pool = special_pool(tier);
+    let grind_bound = u32::from(tier) * 3 + 1;
+    let attribute_bound = u32::from(tier) * 10 + 1;
+
+    (0..count)
+        .map(|_| {
+            let (name, damage, value, weight) =
+                BASE_WEAPONS[rng.gen_range(BASE_WEAPONS.len() as u32) as usize];
+            let grind = rng.gen_range(grind_bound) as u8;
+
+            let special = if !pool.is_empty() && rng.gen_range(4) == 0 {
+                Some(pool[rng.gen_range(pool.len() as u32) as usize])
+            } else {
+                None
+            };
+
+            let mut attributes: [Option<(Element, i8)>; 3] = [None, None, None];
+            for slot in attributes.iter_mut() {
+                if rng.gen_range(3) == 0 {
+                    let element = ELEMENTS[rng.gen_range(ELEMENTS.len() as u32) as usize];
+                    let percent = rng.gen_range(attribute_bound) as i8;
+                    *slot = Some((element, percent));
+                }
+            }
+
+            let special_bonus = special.map(|s| s.value_bonus_percent()).unwrap_or(0);
+            let grind_bonus = u32::from(grind) * 5;
+            let scaled_value = value + value * (special_bonus + grind_bonus) / 100;
+            let scaled_damage = damage + damage * u32::from(grind) / 10;
+
+            Weapon {
+                name: name.to_string(),
+                damage: scaled_damage,
+                value: scaled_value,
+                weight,
+                grind,
+                special,
+                attributes,
+            }
+        })
+        .collect()
+}
+
+/// Renders the non-empty attribute slots as e.g. ", Fire+10%/Ice-5%", or an
+/// empty string if every slot is empty. Used by `Weapon`'s `Display` impl.
+pub fn attributes_display(attributes: &[Option<(Element, i8)>; 3]) -> String {
+    let parts: Vec<String> = attributes
+        .iter()
+        .filter_map(|slot| slot.as_ref())
+        .map(|(element, percent)| format!("{}{:+}%", element.name(), percent))
+        .collect();
+
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(", {}", parts.join("/"))
+    }
+}
+
+/// Parses the `;`-joined `attributes` field value produced by `Weapon::serialize`.
+pub fn parse_attributes(value: &str) -> Result<[Option<(Element, i8)>; 3], String> {
+    let mut attributes: [Option<(Element, i8)>; 3] = [None, None, None];
+
+    for (i, slot) in value.split(';').enumerate().take(3) {
+        if slot == "None" {
+            continue;
+        }
+        let (element_name, percent) = slot
+            .split_once('=')
+            .ok_or_else(|| format!("Malformed attribute: {}", slot))?;
+        let element = Element::from_name(element_name)
+            .ok_or_else(|| format!("Invalid element: {}", element_name))?;
+        let percent: i8 = percent
+            .parse()
+            .map_err(|_| "Invalid attribute percent".to_string())?;
+        attributes[i] = Some((element, percent));
+    }
+
+    Ok(attributes)
+}
+
+/// Serializes the attribute slots to the `;`-joined form `parse_attributes` reads.
+pub fn serialize_attributes(attributes: &[Option<(Element, i8)>; 3]) -> String {
+    attributes
+        .iter()
+        .map(|slot| match slot {
+            Some((element, percent)) => format!("{}={}", element.name(), percent),
+            None => "None".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
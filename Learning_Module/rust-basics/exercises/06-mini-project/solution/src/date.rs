@@ -0,0 +1,200 @@
+// Minimal calendar date handling for task due dates and recurrence.
+// In a real app this would be backed by the chrono crate plus a
+// kairos-style relative-date parser; here it's hand-rolled against std
+// so the exercise doesn't need an external dependency.
+
+use crate::error::TaskError;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Self {
+        Date { year, month, day }
+    }
+
+    /// Today's date, derived from the system clock.
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| (d.as_secs() / 86_400) as i64)
+            .unwrap_or(0);
+        Self::from_days_since_epoch(days)
+    }
+
+    // civil_from_days (Howard Hinnant's algorithm), proleptic Gregorian calendar.
+    fn from_days_since_epoch(days: i64) -> Self {
+        let z = days + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097; // [0, 146096]
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+        let mp = (5 * doy + 2) / 153; // [0, 11]
+        let d = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+        let m = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+        let year = if m <= 2 { y + 1 } else { y };
+        Date {
+            year: year as i32,
+            month: m as u32,
+            day: d as u32,
+        }
+    }
+
+    // days_from_civil, the inverse of from_days_since_epoch.
+    fn to_days_since_epoch(self) -> i64 {
+        let y: i64 = if self.month <= 2 {
+            self.year as i64 - 1
+        } else {
+            self.year as i64
+        };
+        let m = self.month as i64;
+        let d = self.day as i64;
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400; // [0, 399]
+        let mp = m + if m > 2 { -3 } else { 9 }; // [0, 11]
+        let doy = (153 * mp + 2) / 5 + d - 1; // [0, 365]
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+        era * 146097 + doe - 719468
+    }
+
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.to_days_since_epoch() + days)
+    }
+
+    /// ISO weekday: 0 = Monday ... 6 = Sunday.
+    pub fn weekday(self) -> u32 {
+        let days = self.to_days_since_epoch();
+        // 1970-01-01 was a Thursday (index 3).
+        ((days.rem_euclid(7) + 3) % 7) as u32
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = TaskError;
+
+    fn from_str(s: &str) -> Result<Self, TaskError> {
+        let parts: Vec<&str> = s.split('-').collect();
+        if parts.len() != 3 {
+            return Err(TaskError::ParseError(format!("Invalid date: {}", s)));
+        }
+
+        let year = parts[0]
+            .parse()
+            .map_err(|_| TaskError::ParseError(format!("Invalid date: {}", s)))?;
+        let month = parts[1]
+            .parse()
+            .map_err(|_| TaskError::ParseError(format!("Invalid date: {}", s)))?;
+        let day = parts[2]
+            .parse()
+            .map_err(|_| TaskError::ParseError(format!("Invalid date: {}", s)))?;
+
+        Ok(Date { year, month, day })
+    }
+}
+
+/// Parses ISO dates (`2024-03-01`) as well as a handful of common relative
+/// phrases: `today`, `tomorrow`, `in N days`, and `next <weekday>`.
+pub fn parse_natural(input: &str, today: Date) -> Result<Date, TaskError> {
+    let s = input.trim().to_lowercase();
+
+    match s.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return Ok(today.add_days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = s.strip_prefix("in ") {
+        let rest = rest.trim_end_matches(" days").trim_end_matches(" day");
+        if let Ok(n) = rest.trim().parse::<i64>() {
+            return Ok(today.add_days(n));
+        }
+    }
+
+    if let Some(day_name) = s.strip_prefix("next ") {
+        let target = weekday_from_name(day_name)?;
+        let current = today.weekday();
+        let mut delta = (target as i64 - current as i64).rem_euclid(7);
+        if delta == 0 {
+            delta = 7; // "next friday" on a Friday means the one after
+        }
+        return Ok(today.add_days(delta));
+    }
+
+    Date::from_str(&s)
+}
+
+fn weekday_from_name(name: &str) -> Result<u32, TaskError> {
+    match name {
+        "monday" => Ok(0),
+        "tuesday" => Ok(1),
+        "wednesday" => Ok(2),
+        "thursday" => Ok(3),
+        "friday" => Ok(4),
+        "saturday" => Ok(5),
+        "sunday" => Ok(6),
+        _ => Err(TaskError::ParseError(format!("Unknown weekday: {}", name))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurRule {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl RecurRule {
+    /// Advances `date` by one occurrence of this rule.
+    pub fn advance(self, date: Date) -> Date {
+        match self {
+            RecurRule::Daily => date.add_days(1),
+            RecurRule::Weekly => date.add_days(7),
+            RecurRule::Monthly => {
+                let mut month = date.month + 1;
+                let mut year = date.year;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+                Date { year, month, day: date.day }
+            }
+        }
+    }
+}
+
+impl fmt::Display for RecurRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecurRule::Daily => write!(f, "daily"),
+            RecurRule::Weekly => write!(f, "weekly"),
+            RecurRule::Monthly => write!(f, "monthly"),
+        }
+    }
+}
+
+impl FromStr for RecurRule {
+    type Err = TaskError;
+
+    fn from_str(s: &str) -> Result<Self, TaskError> {
+        match s.to_lowercase().as_str() {
+            "daily" => Ok(RecurRule::Daily),
+            "weekly" => Ok(RecurRule::Weekly),
+            "monthly" => Ok(RecurRule::Monthly),
+            _ => Err(TaskError::ParseError(format!("Invalid recurrence: {}", s))),
+        }
+    }
+}
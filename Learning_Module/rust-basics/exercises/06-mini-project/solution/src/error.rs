@@ -11,6 +11,9 @@ pub enum TaskError {
     InvalidPriority(String),
     InvalidStatus(String),
     SerializationError(String),
+    DependencyCycle(Vec<u32>),
+    BlockedByDependency(u32),
+    IntegrityError(String),
 }
 
 impl fmt::Display for TaskError {
@@ -23,6 +26,18 @@ impl fmt::Display for TaskError {
             TaskError::InvalidPriority(p) => write!(f, "Invalid priority: {}", p),
             TaskError::InvalidStatus(s) => write!(f, "Invalid status: {}", s),
             TaskError::SerializationError(msg) => write!(f, "Serialization error: {}", msg),
+            TaskError::DependencyCycle(path) => {
+                let path_str = path
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                write!(f, "Dependency cycle detected: {}", path_str)
+            }
+            TaskError::BlockedByDependency(id) => {
+                write!(f, "Task #{} is not yet completed", id)
+            }
+            TaskError::IntegrityError(msg) => write!(f, "Integrity error: {}", msg),
         }
     }
 }
@@ -33,4 +48,11 @@ impl From<std::io::Error> for TaskError {
     }
 }
 
-impl std::error::Error for TaskError {}
+impl std::error::Error for TaskError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TaskError::IoError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,228 @@
+// A small microKanren-style relational query layer, so callers can compose
+// logic goals over task facts instead of writing ad-hoc `iter().find()`
+// loops. See http://minikanren.org for background on the technique this is
+// modeled on.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    Var(u32),
+    Atom(String),
+    Pair(Box<Term>, Box<Term>),
+}
+
+impl Term {
+    pub fn atom(value: impl Into<String>) -> Term {
+        Term::Atom(value.into())
+    }
+
+    pub fn pair(a: Term, b: Term) -> Term {
+        Term::Pair(Box::new(a), Box::new(b))
+    }
+}
+
+/// A substitution map plus a fresh-variable counter, threaded through every
+/// goal in a query.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    subst: HashMap<u32, Term>,
+    next_var: u32,
+}
+
+impl State {
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Resolves `term` against this state's substitution - the public face
+    /// of `walk` for callers outside this module that just want a binding.
+    pub fn resolve(&self, term: &Term) -> Term {
+        walk(term, &self.subst)
+    }
+}
+
+/// Chases `term` through `subst` until it reaches an unbound variable or a
+/// non-variable term.
+pub fn walk(term: &Term, subst: &HashMap<u32, Term>) -> Term {
+    match term {
+        Term::Var(id) => match subst.get(id) {
+            Some(bound) => walk(bound, subst),
+            None => term.clone(),
+        },
+        other => other.clone(),
+    }
+}
+
+/// Attempts to unify `u` and `v` under `subst`, returning the extended
+/// substitution on success. Binds a variable to a term, recurses structurally
+/// on pairs, and fails on atom mismatch.
+pub fn unify(u: &Term, v: &Term, subst: &HashMap<u32, Term>) -> Option<HashMap<u32, Term>> {
+    let u = walk(u, subst);
+    let v = walk(v, subst);
+
+    match (&u, &v) {
+        (Term::Var(a), Term::Var(b)) if a == b => Some(subst.clone()),
+        (Term::Var(a), _) => {
+            let mut next = subst.clone();
+            next.insert(*a, v);
+            Some(next)
+        }
+        (_, Term::Var(b)) => {
+            let mut next = subst.clone();
+            next.insert(*b, u);
+            Some(next)
+        }
+        (Term::Atom(a), Term::Atom(b)) if a == b => Some(subst.clone()),
+        (Term::Pair(a1, a2), Term::Pair(b1, b2)) => {
+            let next = unify(a1, b1, subst)?;
+            unify(a2, b2, &next)
+        }
+        _ => None,
+    }
+}
+
+/// A goal takes a `State` and lazily produces the states that satisfy it.
+pub type Goal = Rc<dyn Fn(State) -> Box<dyn Iterator<Item = State>>>;
+
+/// Unifies `u` and `v`, yielding zero or one state.
+pub fn eq(u: Term, v: Term) -> Goal {
+    Rc::new(move |state: State| match unify(&u, &v, &state.subst) {
+        Some(subst) => Box::new(std::iter::once(State {
+            subst,
+            next_var: state.next_var,
+        })),
+        None => Box::new(std::iter::empty()),
+    })
+}
+
+/// Allocates a fresh variable, increments the counter, and passes the
+/// variable to `f` to build the goal that uses it.
+pub fn fresh(f: impl Fn(Term) -> Goal + 'static) -> Goal {
+    Rc::new(move |state: State| {
+        let var = Term::Var(state.next_var);
+        let goal = f(var);
+        goal(State {
+            subst: state.subst,
+            next_var: state.next_var + 1,
+        })
+    })
+}
+
+/// Fairly interleaves the result streams of `a` and `b` - alternating
+/// elements rather than draining one before the other - so disjunctions of
+/// infinite relations stay productive.
+pub fn disj(a: Goal, b: Goal) -> Goal {
+    Rc::new(move |state: State| {
+        Box::new(Interleave {
+            left: a(state.clone()),
+            right: b(state),
+            take_left: true,
+        }) as Box<dyn Iterator<Item = State>>
+    })
+}
+
+struct Interleave {
+    left: Box<dyn Iterator<Item = State>>,
+    right: Box<dyn Iterator<Item = State>>,
+    take_left: bool,
+}
+
+impl Iterator for Interleave {
+    type Item = State;
+
+    fn next(&mut self) -> Option<State> {
+        let (first, second) = if self.take_left {
+            (&mut self.left, &mut self.right)
+        } else {
+            (&mut self.right, &mut self.left)
+        };
+
+        match first.next() {
+            Some(state) => {
+                self.take_left = !self.take_left;
+                Some(state)
+            }
+            None => second.next(),
+        }
+    }
+}
+
+/// Runs `b` over every state produced by `a` - a flat-map over the two
+/// result streams.
+pub fn conj(a: Goal, b: Goal) -> Goal {
+    Rc::new(move |state: State| {
+        let b = Rc::clone(&b);
+        Box::new(a(state).flat_map(move |s| b(s)))
+    })
+}
+
+/// Builds a goal that succeeds once per fact in `facts`, unifying `target`
+/// against each in turn (fair disjunction, so the fact list can grow large
+/// without starving later facts).
+pub fn facts_goal(facts: Vec<Term>, target: Term) -> Goal {
+    facts.into_iter().fold(
+        Rc::new(|_: State| Box::new(std::iter::empty()) as Box<dyn Iterator<Item = State>>) as Goal,
+        |acc, fact| disj(acc, eq(target.clone(), fact)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unify_fails_on_atom_mismatch() {
+        let subst = HashMap::new();
+        let result = unify(&Term::atom("a"), &Term::atom("b"), &subst);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn unify_succeeds_on_matching_atoms() {
+        let subst = HashMap::new();
+        let result = unify(&Term::atom("a"), &Term::atom("a"), &subst);
+        assert_eq!(result, Some(subst));
+    }
+
+    #[test]
+    fn disj_fairly_interleaves_two_streams() {
+        let goal = disj(
+            eq(Term::Var(0), Term::atom("left")),
+            eq(Term::Var(0), Term::atom("right")),
+        );
+        let results: Vec<Term> = goal(State::new())
+            .map(|state| state.resolve(&Term::Var(0)))
+            .collect();
+        assert_eq!(results, vec![Term::atom("left"), Term::atom("right")]);
+    }
+
+    #[test]
+    fn facts_goal_interleaves_many_facts_fairly() {
+        // facts_goal builds a left-leaning tree of disj calls, so the order
+        // comes out "one, three, two" rather than input order - this pins
+        // that shape rather than re-deriving it from disj's interleaving.
+        let facts = vec![Term::atom("one"), Term::atom("two"), Term::atom("three")];
+        let goal = facts_goal(facts, Term::Var(0));
+        let results: Vec<Term> = goal(State::new())
+            .map(|state| state.resolve(&Term::Var(0)))
+            .collect();
+        assert_eq!(
+            results,
+            vec![Term::atom("one"), Term::atom("three"), Term::atom("two")]
+        );
+    }
+
+    #[test]
+    fn conj_joins_across_multiple_variables() {
+        let goal = conj(
+            fresh(|a| eq(a, Term::atom("x"))),
+            fresh(|b| eq(b, Term::atom("y"))),
+        );
+        let states: Vec<State> = goal(State::new()).collect();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].resolve(&Term::Var(0)), Term::atom("x"));
+        assert_eq!(states[0].resolve(&Term::Var(1)), Term::atom("y"));
+    }
+}
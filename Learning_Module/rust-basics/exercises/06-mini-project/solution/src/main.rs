@@ -1,14 +1,18 @@
 // Task Manager CLI - Main Entry Point
 
+mod date;
 mod error;
+mod kanren;
 mod manager;
+mod server;
 mod storage;
 mod task;
 
+use date::{parse_natural, Date, RecurRule};
 use error::TaskError;
-use manager::TaskManager;
-use storage::{FileStorage, Storage};
-use task::{Priority, Status, Task};
+use manager::{TaskAction, TaskManager, TaskQuery};
+use storage::{FileStorage, JsonStorage, Storage};
+use task::{Priority, Serializable, Status, Task};
 
 use std::collections::HashMap;
 use std::io::{self, Write};
@@ -63,6 +67,19 @@ fn main() {
                 handle_stats_command(&manager);
                 Ok(())
             }
+            Some(&"export") => handle_export_command(&manager, &parts[1..]),
+            Some(&"import") => handle_import_command(&mut manager, &parts[1..]),
+            Some(&"backup") => handle_backup_command(&manager, &parts[1..]),
+            Some(&"restore") => handle_restore_command(&mut manager, &parts[1..]),
+            Some(&"undo") => manager.undo(),
+            Some(&"redo") => manager.redo(),
+            Some(&"tick") => {
+                handle_tick_command(&mut manager);
+                Ok(())
+            }
+            Some(&"query") => handle_query_command(&manager, &parts[1..]),
+            Some(&"plan") => handle_plan_command(&manager),
+            Some(&"serve") => handle_serve_command(&manager, &storage, &parts[1..]),
             Some(&"help") => {
                 show_help();
                 Ok(())
@@ -76,7 +93,18 @@ fn main() {
 
         if let Err(e) = result {
             println!("✗ Error: {}", e);
-        } else if matches!(parts.get(0), Some(&"add") | Some(&"update") | Some(&"complete") | Some(&"delete")) {
+        } else if matches!(
+            parts.get(0),
+            Some(&"add")
+                | Some(&"update")
+                | Some(&"complete")
+                | Some(&"delete")
+                | Some(&"import")
+                | Some(&"restore")
+                | Some(&"undo")
+                | Some(&"redo")
+                | Some(&"tick")
+        ) {
             // Save after modifications
             if let Err(e) = storage.save(manager.list_tasks()) {
                 println!("⚠ Warning: Could not save tasks: {}", e);
@@ -96,48 +124,108 @@ fn handle_add_command(manager: &mut TaskManager<Task>, args: &[&str]) -> Result<
 
     let title = args[0].to_string();
     let priority = Priority::from_str(args[1])?;
-    let category = args.get(2).map(|s| s.to_string());
 
-    let id = manager.count() as u32 + 1;
+    let mut category = None;
+    let mut depends_on = Vec::new();
+    let mut due = None;
+    let mut recur = None;
+    let mut staleness = None;
+    for arg in &args[2..] {
+        if let Some(value) = arg.strip_prefix("depends=") {
+            depends_on = parse_depends_on(value)?;
+        } else if let Some(value) = arg.strip_prefix("due=") {
+            due = Some(parse_natural(value, Date::today())?);
+        } else if let Some(value) = arg.strip_prefix("recur=") {
+            recur = Some(RecurRule::from_str(value)?);
+        } else if let Some(value) = arg.strip_prefix("staleness=") {
+            staleness = Some(
+                value
+                    .parse::<u32>()
+                    .map_err(|_| TaskError::ParseError(format!("Invalid staleness: {}", value)))?,
+            );
+        } else {
+            category = Some(arg.to_string());
+        }
+    }
+
+    let id = manager.peek_next_id();
     let mut task = Task::new(id, title, priority)?;
     if let Some(cat) = category {
         task = task.with_category(cat);
     }
+    task = task.with_depends_on(depends_on);
+    if let Some(due) = due {
+        task = task.with_due(due);
+    }
+    if let Some(recur) = recur {
+        task = task.with_recur(recur);
+    }
+    if let Some(staleness) = staleness {
+        task = task.with_staleness(staleness);
+    }
 
-    manager.add_task(task.clone());
     println!("✓ Task #{} created: {} [{}]", id, task.title, task.priority);
+    manager.dispatch(TaskAction::Add(task))
+}
 
-    Ok(())
+fn parse_depends_on(value: &str) -> Result<Vec<u32>, TaskError> {
+    value
+        .split(',')
+        .map(|id| {
+            id.parse()
+                .map_err(|_| TaskError::ParseError(format!("Invalid dependency ID: {}", id)))
+        })
+        .collect()
 }
 
 fn handle_list_command(manager: &TaskManager<Task>, args: &[&str]) {
-    let tasks = manager.list_tasks();
-
-    // Parse filters
-    let mut status_filter: Option<Status> = None;
-    let mut priority_filter: Option<Priority> = None;
+    // Status/priority/title/limit/ordering are handled by the composable
+    // `TaskQuery`/`search`; `ready`/`overdue`/`due` depend on `today` or
+    // cross-task dependency state and `TaskQuery` has no category field, so
+    // `ready`/`overdue`/`category`/`due` stay as a post-filter.
+    let mut query = TaskQuery::new();
     let mut category_filter: Option<String> = None;
+    let ready_only = args.contains(&"ready");
+    let overdue_only = args.contains(&"overdue");
+    let sort_overdue_first = args.contains(&"sort=overdue");
+    let mut due_filter: Option<Date> = None;
+    let today = Date::today();
 
     for arg in args {
         if let Some(value) = arg.strip_prefix("status=") {
-            status_filter = Status::from_str(value).ok();
+            if let Ok(status) = Status::from_str(value) {
+                query = query.status(status);
+            }
         } else if let Some(value) = arg.strip_prefix("priority=") {
-            priority_filter = Priority::from_str(value).ok();
+            if let Ok(priority) = Priority::from_str(value) {
+                query = query.priority(priority);
+            }
         } else if let Some(value) = arg.strip_prefix("category=") {
             category_filter = Some(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("due=") {
+            due_filter = parse_natural(value, today).ok();
+        } else if let Some(value) = arg.strip_prefix("title=") {
+            query = query.title_contains(value.to_string());
+        } else if let Some(value) = arg.strip_prefix("limit=") {
+            if let Ok(limit) = value.parse() {
+                query = query.limit(limit);
+            }
         }
     }
+    query = query.overdue_first(sort_overdue_first);
 
-    let filtered: Vec<&Task> = tasks
-        .iter()
+    let filtered: Vec<&Task> = manager
+        .search(&query)
+        .into_iter()
         .filter(|t| {
-            if let Some(ref s) = status_filter {
-                if &t.status != s {
-                    return false;
-                }
+            if ready_only && (t.is_completed() || !manager.dependencies_satisfied(t.id)) {
+                return false;
+            }
+            if overdue_only && !t.is_overdue(today) {
+                return false;
             }
-            if let Some(ref p) = priority_filter {
-                if &t.priority != p {
+            if let Some(due) = due_filter {
+                if t.due != Some(due) {
                     return false;
                 }
             }
@@ -188,6 +276,26 @@ fn handle_show_command(manager: &TaskManager<Task>, args: &[&str]) -> Result<(),
         task.description.as_deref().unwrap_or("None")
     );
     println!("  Created: {}", task.created_at);
+    println!(
+        "  Due: {}",
+        task.due.map(|d| d.to_string()).unwrap_or_else(|| "None".to_string())
+    );
+    println!(
+        "  Recurs: {}",
+        task.recur.map(|r| r.to_string()).unwrap_or_else(|| "None".to_string())
+    );
+    if task.depends_on.is_empty() {
+        println!("  Depends on: None");
+    } else {
+        println!(
+            "  Depends on: {}",
+            task.depends_on
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 
     Ok(())
 }
@@ -206,34 +314,47 @@ fn handle_update_command(
         .parse()
         .map_err(|_| TaskError::ParseError("Invalid task ID".to_string()))?;
 
-    let task = manager
-        .get_task_by_id_mut(id)
-        .ok_or(TaskError::NotFound(id))?;
+    let mut updated = manager
+        .get_task_by_id(id)
+        .ok_or(TaskError::NotFound(id))?
+        .clone();
 
     match args[1] {
         "status" => {
             let status = Status::from_str(args[2])?;
-            task.set_status(status);
+            updated.set_status(status);
             println!("✓ Task #{} updated: status = {}", id, args[2]);
         }
         "priority" => {
             let priority = Priority::from_str(args[2])?;
-            task.set_priority(priority);
+            updated.set_priority(priority);
             println!("✓ Task #{} updated: priority = {}", id, args[2]);
         }
         "title" => {
-            task.title = args[2..].join(" ");
-            println!("✓ Task #{} updated: title = {}", id, task.title);
+            updated.title = args[2..].join(" ");
+            println!("✓ Task #{} updated: title = {}", id, updated.title);
+        }
+        "depends" => {
+            updated.depends_on = parse_depends_on(args[2])?;
+            println!("✓ Task #{} updated: depends = {}", id, args[2]);
+        }
+        "due" => {
+            updated.due = Some(parse_natural(args[2], Date::today())?);
+            println!("✓ Task #{} updated: due = {}", id, args[2]);
+        }
+        "recur" => {
+            updated.recur = Some(RecurRule::from_str(args[2])?);
+            println!("✓ Task #{} updated: recur = {}", id, args[2]);
         }
         _ => {
             return Err(TaskError::ValidationError(format!(
-                "Unknown field: {}. Valid fields: status, priority, title",
+                "Unknown field: {}. Valid fields: status, priority, title, depends, due, recur",
                 args[1]
             )));
         }
     }
 
-    Ok(())
+    manager.dispatch(TaskAction::Update(updated))
 }
 
 fn handle_complete_command(
@@ -250,13 +371,17 @@ fn handle_complete_command(
         .parse()
         .map_err(|_| TaskError::ParseError("Invalid task ID".to_string()))?;
 
-    let task = manager
-        .get_task_by_id_mut(id)
-        .ok_or(TaskError::NotFound(id))?;
+    if let Some(blocker) = manager.first_unmet_dependency(id) {
+        return Err(TaskError::BlockedByDependency(blocker));
+    }
 
-    task.set_status(Status::Completed);
+    manager.dispatch(TaskAction::Complete(id))?;
     println!("✓ Task #{} marked as completed", id);
 
+    if let Some(new_id) = manager.spawn_next_recurrence(id) {
+        println!("✓ Recurring task #{} spawned as #{}", id, new_id);
+    }
+
     Ok(())
 }
 
@@ -274,12 +399,121 @@ fn handle_delete_command(
         .parse()
         .map_err(|_| TaskError::ParseError("Invalid task ID".to_string()))?;
 
-    manager.remove_task_by_id(id)?;
+    manager.dispatch(TaskAction::Delete(id))?;
     println!("✓ Task #{} deleted", id);
 
     Ok(())
 }
 
+fn handle_export_command(manager: &TaskManager<Task>, args: &[&str]) -> Result<(), TaskError> {
+    if args.is_empty() {
+        return Err(TaskError::ValidationError(
+            "Usage: export <file>".to_string(),
+        ));
+    }
+
+    let storage = JsonStorage::new(args[0].to_string());
+    storage.save(manager.list_tasks())?;
+    println!(
+        "✓ Exported {} tasks to {}",
+        manager.count(),
+        args[0]
+    );
+
+    Ok(())
+}
+
+fn handle_import_command(
+    manager: &mut TaskManager<Task>,
+    args: &[&str],
+) -> Result<(), TaskError> {
+    if args.is_empty() {
+        return Err(TaskError::ValidationError(
+            "Usage: import <file>".to_string(),
+        ));
+    }
+
+    let storage = JsonStorage::new(args[0].to_string());
+    let imported = storage.load()?;
+
+    let mut added = 0;
+    for task in imported {
+        if manager.get_task_by_id(task.id).is_some() {
+            continue; // merge by uuid: skip tasks we already have
+        }
+        manager.add_task(task);
+        added += 1;
+    }
+
+    println!("✓ Imported {} new tasks from {}", added, args[0]);
+
+    Ok(())
+}
+
+/// Full-fidelity JSON-lines backup (one `Task::serialize_json` object per
+/// line), unlike `export`'s lossy Taskwarrior-compatible `JsonStorage`.
+fn handle_backup_command(manager: &TaskManager<Task>, args: &[&str]) -> Result<(), TaskError> {
+    if args.is_empty() {
+        return Err(TaskError::ValidationError(
+            "Usage: backup <file>".to_string(),
+        ));
+    }
+
+    let body = manager
+        .list_tasks()
+        .iter()
+        .map(|task| task.serialize_json())
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(args[0], body)?;
+    println!("✓ Backed up {} tasks to {}", manager.count(), args[0]);
+
+    Ok(())
+}
+
+/// Restores tasks from a `backup` file, via `Serializable::deserialize`'s
+/// format auto-detection.
+fn handle_restore_command(
+    manager: &mut TaskManager<Task>,
+    args: &[&str],
+) -> Result<(), TaskError> {
+    if args.is_empty() {
+        return Err(TaskError::ValidationError(
+            "Usage: restore <file>".to_string(),
+        ));
+    }
+
+    let content = std::fs::read_to_string(args[0])?;
+    let mut added = 0;
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let task = Task::deserialize(line)?;
+        if manager.get_task_by_id(task.id).is_some() {
+            continue;
+        }
+        manager.add_task(task);
+        added += 1;
+    }
+    println!("✓ Restored {} new tasks from {}", added, args[0]);
+
+    Ok(())
+}
+
+fn handle_serve_command(
+    manager: &TaskManager<Task>,
+    storage: &FileStorage,
+    args: &[&str],
+) -> Result<(), TaskError> {
+    let addr = args.first().copied().unwrap_or("127.0.0.1:8080");
+    // The REST server runs on its own cloned, mutex-guarded copy of the task
+    // list. This call blocks while serving and returns an error if `addr`
+    // cannot be bound or accepting a connection fails.
+    server::serve(addr, manager.clone(), storage.clone())
+        .map_err(TaskError::from)
+}
+
 fn handle_stats_command(manager: &TaskManager<Task>) {
     let tasks = manager.list_tasks();
 
@@ -298,6 +532,8 @@ fn handle_stats_command(manager: &TaskManager<Task>) {
         .iter()
         .filter(|t| t.status == Status::Completed)
         .count();
+    let today = Date::today();
+    let overdue = tasks.iter().filter(|t| t.is_overdue(today)).count();
 
     let mut by_priority: HashMap<Priority, usize> = HashMap::new();
     for task in tasks {
@@ -309,6 +545,7 @@ fn handle_stats_command(manager: &TaskManager<Task>) {
     println!("  Pending: {}", pending);
     println!("  In Progress: {}", in_progress);
     println!("  Completed: {}", completed);
+    println!("  Overdue: {}", overdue);
     println!();
     println!("By Priority:");
     println!("  Critical: {}", by_priority.get(&Priority::Critical).unwrap_or(&0));
@@ -317,17 +554,86 @@ fn handle_stats_command(manager: &TaskManager<Task>) {
     println!("  Low: {}", by_priority.get(&Priority::Low).unwrap_or(&0));
 }
 
+fn handle_tick_command(manager: &mut TaskManager<Task>) {
+    let events = manager.apply_tick();
+    if events.is_empty() {
+        println!("No staleness transitions this tick.");
+    } else {
+        for event in events {
+            println!("{}", event);
+        }
+    }
+}
+
+fn handle_query_command(manager: &TaskManager<Task>, args: &[&str]) -> Result<(), TaskError> {
+    if args.len() < 2 {
+        return Err(TaskError::ValidationError(
+            "Usage: query <status> <priority>".to_string(),
+        ));
+    }
+
+    let status = Status::from_str(args[0])?;
+    let priority = Priority::from_str(args[1])?;
+    let ids = manager.query_by_status_and_priority(&status.to_string(), &priority.to_string());
+
+    if ids.is_empty() {
+        println!("No tasks match.");
+    } else {
+        for id in ids {
+            if let Some(task) = manager.get_task(id) {
+                println!("{}", task);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints tasks in an order that respects `depends_on`, or a
+/// `DependencyCycle` error naming the cycle if the dependency graph isn't a
+/// DAG.
+fn handle_plan_command(manager: &TaskManager<Task>) -> Result<(), TaskError> {
+    let order = manager.resolve_order()?;
+
+    if order.is_empty() {
+        println!("No tasks to plan.");
+    } else {
+        println!("Execution order ({} tasks):", order.len());
+        for id in order {
+            if let Some(task) = manager.get_task_by_id(id) {
+                println!("  #{} {}", id, task.title);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn show_help() {
     println!("Available commands:");
-    println!("  add <title> <priority> [category]  - Add a new task");
+    println!("  add <title> <priority> [category] [depends=<ids>] [due=<date>] [recur=<rule>] [staleness=<n>]  - Add a new task");
     println!("                                       Priority: low, medium, high, critical");
+    println!("                                       depends=<ids> is a comma-separated list of task IDs");
+    println!("                                       due=<date> accepts ISO dates or today/tomorrow/in N days/next <weekday>");
+    println!("                                       recur=<rule> is one of: daily, weekly, monthly");
+    println!("                                       staleness=<n> ticks before the task is auto-flagged overdue");
     println!("  list [filter]                      - List all tasks");
-    println!("                                       Filters: status=<status>, priority=<priority>, category=<category>");
+    println!("                                       Filters: status=<status>, priority=<priority>, category=<category>, title=<substring>, limit=<n>, sort=overdue, ready, overdue, due=<date>");
     println!("  show <id>                          - Show detailed task information");
-    println!("  update <id> <field> <value>        - Update task field (status, priority, title)");
+    println!("  update <id> <field> <value>        - Update task field (status, priority, title, depends, due, recur)");
     println!("  complete <id>                      - Mark task as completed");
     println!("  delete <id>                        - Delete a task");
     println!("  stats                              - Show task statistics");
+    println!("  export <file>                       - Export tasks as Taskwarrior-compatible JSON");
+    println!("  import <file>                       - Import tasks from a Taskwarrior-compatible JSON file");
+    println!("  backup <file>                       - Back up tasks as full-fidelity JSON (one object per line)");
+    println!("  restore <file>                      - Restore tasks from a backup file");
+    println!("  undo                                - Undo the last add/update/complete/delete");
+    println!("  redo                                - Redo the last undone action");
+    println!("  tick                                - Decay staleness counters, auto-flagging tasks overdue");
+    println!("  query <status> <priority>          - List tasks matching both a status and a priority");
+    println!("  plan                                - Print tasks in dependency order (errors on a cycle)");
+    println!("  serve [addr]                        - Serve tasks over REST (default 127.0.0.1:8080)");
     println!("  help                               - Show this help message");
     println!("  quit                               - Exit the application");
 }
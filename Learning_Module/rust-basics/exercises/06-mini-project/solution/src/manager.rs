@@ -1,45 +1,75 @@
 // Task Manager - Generic container for managing tasks
 
 use crate::error::TaskError;
-use crate::task::{Serializable, Task};
+use crate::kanren::{conj, facts_goal, fresh, State, Term};
+use crate::task::{Identifiable, Priority, Serializable, Status, Task};
+use std::collections::HashMap;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+/// A single auditable mutation to the task list. `TaskManager::dispatch`
+/// applies one of these and records its inverse on the undo stack, so
+/// `undo`/`redo` can replay history instead of every command mutating
+/// tasks directly.
+#[derive(Debug, Clone)]
+pub enum TaskAction {
+    Add(Task),
+    Update(Task),
+    Complete(u32),
+    Delete(u32),
+    SetStatus(u32, Status),
+}
+
+#[derive(Clone)]
 pub struct TaskManager<T: Serializable + Clone> {
     tasks: Vec<T>,
     next_id: u32,
+    undo_stack: Vec<TaskAction>,
+    redo_stack: Vec<TaskAction>,
 }
 
-impl<T: Serializable + Clone> TaskManager<T> {
+impl<T: Serializable + Clone + Identifiable> TaskManager<T> {
     pub fn new() -> Self {
         TaskManager {
             tasks: Vec::new(),
             next_id: 1,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
         }
     }
 
     pub fn add_task(&mut self, task: T) -> u32 {
+        let id = task.id();
+        self.next_id = self.next_id.max(id + 1);
         self.tasks.push(task);
-        let id = self.next_id;
-        self.next_id += 1;
         id
     }
 
+    /// The id the next `add_task`/`TaskAction::Add` will be tagged with,
+    /// so callers that build a task before handing it to the manager can
+    /// agree on an id up front instead of guessing from `count()`.
+    pub fn peek_next_id(&self) -> u32 {
+        self.next_id
+    }
+
     pub fn get_task(&self, id: u32) -> Option<&T> {
-        self.tasks.iter().find(|t| {
-            // This is a workaround since we can't access id directly on generic T
-            // In the actual implementation with Task, we'd use task.id
-            true // Placeholder - actual implementation would check ID
-        })
+        self.tasks.iter().find(|t| t.id() == id)
     }
 
     pub fn get_task_mut(&mut self, id: u32) -> Option<&mut T> {
-        self.tasks.iter_mut().find(|_| true) // Placeholder
+        self.tasks.iter_mut().find(|t| t.id() == id)
     }
 
     pub fn remove_task(&mut self, id: u32) -> Result<T, TaskError> {
         let pos = self
             .tasks
             .iter()
-            .position(|_| true) // Placeholder
+            .position(|t| t.id() == id)
             .ok_or(TaskError::NotFound(id))?;
         Ok(self.tasks.remove(pos))
     }
@@ -53,13 +83,91 @@ impl<T: Serializable + Clone> TaskManager<T> {
     }
 
     pub fn load_tasks(&mut self, tasks: Vec<T>) {
+        self.next_id = tasks.iter().map(|t| t.id()).max().unwrap_or(0) + 1;
         self.tasks = tasks;
-        self.next_id = 1; // Would calculate max ID + 1 in real implementation
+    }
+}
+
+/// Composable filter set for `TaskManager::search`, built up one constraint
+/// at a time rather than taking a long positional-argument list.
+#[derive(Debug, Clone, Default)]
+pub struct TaskQuery {
+    status: Option<Status>,
+    priority: Option<Priority>,
+    title_contains: Option<String>,
+    limit: Option<u8>,
+    overdue_first: bool,
+}
+
+impl TaskQuery {
+    pub fn new() -> Self {
+        TaskQuery::default()
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn title_contains(mut self, substring: String) -> Self {
+        self.title_contains = Some(substring);
+        self
+    }
+
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn overdue_first(mut self, overdue_first: bool) -> Self {
+        self.overdue_first = overdue_first;
+        self
+    }
+
+    fn matches(&self, task: &Task) -> bool {
+        if let Some(status) = &self.status {
+            if task.status != *status {
+                return false;
+            }
+        }
+        if let Some(priority) = &self.priority {
+            if task.priority != *priority {
+                return false;
+            }
+        }
+        if let Some(substring) = &self.title_contains {
+            if !task.title.to_lowercase().contains(&substring.to_lowercase()) {
+                return false;
+            }
+        }
+        true
     }
 }
 
 // Specialized implementation for Task type
 impl TaskManager<Task> {
+    /// Filters tasks against `query`, optionally ordering overdue tasks first
+    /// and capping the result count.
+    pub fn search(&self, query: &TaskQuery) -> Vec<&Task> {
+        let mut results: Vec<&Task> = self.tasks.iter().filter(|t| query.matches(t)).collect();
+
+        if query.overdue_first {
+            let today = crate::date::Date::today();
+            results.sort_by_key(|t| !t.is_overdue(today));
+        }
+
+        if let Some(limit) = query.limit {
+            results.truncate(limit as usize);
+        }
+
+        results
+    }
+
     pub fn get_task_by_id(&self, id: u32) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
     }
@@ -82,4 +190,245 @@ impl TaskManager<Task> {
         self.tasks = tasks;
         self.next_id = max_id + 1;
     }
+
+    /// Returns task IDs in an order that respects `depends_on`, via a depth-first
+    /// topological sort. Errors if the dependency graph contains a cycle.
+    pub fn resolve_order(&self) -> Result<Vec<u32>, TaskError> {
+        let mut state: HashMap<u32, VisitState> = self
+            .tasks
+            .iter()
+            .map(|t| (t.id, VisitState::Unvisited))
+            .collect();
+        let mut order = Vec::new();
+
+        for task in &self.tasks {
+            self.visit(task.id, &mut state, &mut Vec::new(), &mut order)?;
+        }
+
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        id: u32,
+        state: &mut HashMap<u32, VisitState>,
+        path: &mut Vec<u32>,
+        order: &mut Vec<u32>,
+    ) -> Result<(), TaskError> {
+        match state.get(&id) {
+            Some(VisitState::Done) => return Ok(()),
+            Some(VisitState::InProgress) => {
+                let mut cycle = path.clone();
+                cycle.push(id);
+                return Err(TaskError::DependencyCycle(cycle));
+            }
+            _ => {}
+        }
+
+        state.insert(id, VisitState::InProgress);
+        path.push(id);
+
+        if let Some(task) = self.get_task_by_id(id) {
+            for dep in task.depends_on.clone() {
+                self.visit(dep, state, path, order)?;
+            }
+        }
+
+        path.pop();
+        state.insert(id, VisitState::Done);
+        order.push(id);
+        Ok(())
+    }
+
+    /// Returns true if every dependency of `id` has status `Completed`.
+    pub fn dependencies_satisfied(&self, id: u32) -> bool {
+        self.first_unmet_dependency(id).is_none()
+    }
+
+    /// Returns the id of the first dependency of `id` that isn't yet
+    /// completed, so a caller can name the actual blocker instead of just
+    /// reporting that `id` is blocked.
+    pub fn first_unmet_dependency(&self, id: u32) -> Option<u32> {
+        match self.get_task_by_id(id) {
+            Some(task) => task
+                .depends_on
+                .iter()
+                .copied()
+                .find(|dep_id| match self.get_task_by_id(*dep_id) {
+                    Some(dep) => !dep.is_completed(),
+                    None => false,
+                }),
+            None => None,
+        }
+    }
+
+    /// If the task is recurring, pushes a fresh instance with `due` advanced
+    /// by its recurrence interval, carrying over title/priority/category.
+    /// Returns the new task's id, if one was spawned.
+    pub fn spawn_next_recurrence(&mut self, id: u32) -> Option<u32> {
+        let task = self.get_task_by_id(id)?.clone();
+        let recur = task.recur?;
+        let due = task.due?;
+
+        // Derived from the current max id rather than trusted to `next_id`
+        // directly, so a spawn can never collide even if some other
+        // insertion path left `next_id` stale.
+        let new_id = self.tasks.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+        self.next_id = self.next_id.max(new_id + 1);
+
+        let mut next = task;
+        next.id = new_id;
+        next.status = Status::Pending;
+        next.due = Some(recur.advance(due));
+
+        self.tasks.push(next);
+        Some(new_id)
+    }
+
+    /// Represents each task's status as a `status(Id, Status)` fact for the
+    /// relational query layer in `kanren`.
+    fn status_facts(&self) -> Vec<Term> {
+        self.tasks
+            .iter()
+            .map(|t| Term::pair(Term::atom(t.id.to_string()), Term::atom(t.status.to_string())))
+            .collect()
+    }
+
+    /// Represents each task's priority as a `priority(Id, Priority)` fact.
+    fn priority_facts(&self) -> Vec<Term> {
+        self.tasks
+            .iter()
+            .map(|t| Term::pair(Term::atom(t.id.to_string()), Term::atom(t.priority.to_string())))
+            .collect()
+    }
+
+    /// Finds ids of tasks whose status and priority both match, via a
+    /// microKanren-style conjunction of two fact-list goals joined on the
+    /// shared `id` variable, rather than an ad-hoc double `iter().filter()`.
+    pub fn query_by_status_and_priority(&self, status: &str, priority: &str) -> Vec<u32> {
+        let status_facts = self.status_facts();
+        let priority_facts = self.priority_facts();
+        let status_atom = Term::atom(status);
+        let priority_atom = Term::atom(priority);
+
+        let goal = fresh(move |id| {
+            conj(
+                facts_goal(status_facts.clone(), Term::pair(id.clone(), status_atom.clone())),
+                facts_goal(priority_facts.clone(), Term::pair(id.clone(), priority_atom.clone())),
+            )
+        });
+
+        goal(State::new())
+            .filter_map(|state| match state.resolve(&Term::Var(0)) {
+                Term::Atom(id) => id.parse().ok(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Decrements every task's `staleness` counter by one tick. A task whose
+    /// counter reaches zero is auto-flagged overdue (its `due` date is
+    /// pulled into the past, the same signal `is_overdue` already checks)
+    /// and stops ticking. Returns a human-readable event per transition.
+    pub fn apply_tick(&mut self) -> Vec<String> {
+        let mut events = Vec::new();
+
+        for task in self.tasks.iter_mut() {
+            if let Some(last_value) = task.staleness {
+                let decayed = last_value.saturating_sub(1);
+                if decayed == 0 {
+                    task.due = Some(crate::date::Date::today().add_days(-1));
+                    task.staleness = None;
+                    events.push(format!("Task #{} is now overdue", task.id));
+                } else {
+                    task.staleness = Some(decayed);
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Applies `action`, pushing its inverse onto the undo stack and
+    /// clearing any redo history (the standard editor convention: a fresh
+    /// edit invalidates previously-undone redos).
+    pub fn dispatch(&mut self, action: TaskAction) -> Result<(), TaskError> {
+        let inverse = self.apply(action)?;
+        self.undo_stack.push(inverse);
+        self.redo_stack.clear();
+        Ok(())
+    }
+
+    pub fn undo(&mut self) -> Result<(), TaskError> {
+        let action = self
+            .undo_stack
+            .pop()
+            .ok_or_else(|| TaskError::ValidationError("Nothing to undo".to_string()))?;
+        let inverse = self.apply(action)?;
+        self.redo_stack.push(inverse);
+        Ok(())
+    }
+
+    pub fn redo(&mut self) -> Result<(), TaskError> {
+        let action = self
+            .redo_stack
+            .pop()
+            .ok_or_else(|| TaskError::ValidationError("Nothing to redo".to_string()))?;
+        let inverse = self.apply(action)?;
+        self.undo_stack.push(inverse);
+        Ok(())
+    }
+
+    /// Applies `action` to the task list and returns the action that would
+    /// undo it.
+    fn apply(&mut self, action: TaskAction) -> Result<TaskAction, TaskError> {
+        match action {
+            TaskAction::Add(task) => {
+                let id = task.id;
+                self.next_id = self.next_id.max(id + 1);
+                self.tasks.push(task);
+                Ok(TaskAction::Delete(id))
+            }
+            TaskAction::Delete(id) => {
+                let pos = self
+                    .tasks
+                    .iter()
+                    .position(|t| t.id == id)
+                    .ok_or(TaskError::NotFound(id))?;
+                let removed = self.tasks.remove(pos);
+                Ok(TaskAction::Add(removed))
+            }
+            TaskAction::Update(new_task) => {
+                let id = new_task.id;
+                let slot = self
+                    .tasks
+                    .iter_mut()
+                    .find(|t| t.id == id)
+                    .ok_or(TaskError::NotFound(id))?;
+                let old = slot.clone();
+                *slot = new_task;
+                Ok(TaskAction::Update(old))
+            }
+            TaskAction::SetStatus(id, status) => {
+                let task = self
+                    .tasks
+                    .iter_mut()
+                    .find(|t| t.id == id)
+                    .ok_or(TaskError::NotFound(id))?;
+                let old_status = task.status.clone();
+                task.status = status;
+                Ok(TaskAction::SetStatus(id, old_status))
+            }
+            TaskAction::Complete(id) => {
+                let task = self
+                    .tasks
+                    .iter_mut()
+                    .find(|t| t.id == id)
+                    .ok_or(TaskError::NotFound(id))?;
+                let old_status = task.status.clone();
+                task.status = Status::Completed;
+                Ok(TaskAction::SetStatus(id, old_status))
+            }
+        }
+    }
 }
@@ -0,0 +1,302 @@
+// Embedded HTTP/REST server mode - exposes the TaskManager over plain
+// std::net sockets since the exercise has no external HTTP framework
+// dependency available. A real app would sit this behind something like
+// an axum/nickel-style router instead of hand-parsing requests.
+
+use crate::error::TaskError;
+use crate::manager::{TaskAction, TaskManager};
+use crate::storage::{extract_field, task_to_json, FileStorage, Storage};
+use crate::task::{Priority, Status, Task};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    body: String,
+}
+
+/// Runs the REST server on `addr`, guarding the shared `TaskManager` behind
+/// a mutex so concurrent requests are safe. Never returns under normal
+/// operation - each connection is handled on its own thread.
+pub fn serve(addr: &str, manager: TaskManager<Task>, storage: FileStorage) -> std::io::Result<()> {
+    let manager = Arc::new(Mutex::new(manager));
+    let storage = Arc::new(storage);
+    let listener = TcpListener::bind(addr)?;
+    println!("✓ Serving tasks at http://{}", addr);
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let manager = Arc::clone(&manager);
+        let storage = Arc::clone(&storage);
+        std::thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, &manager, &storage) {
+                println!("⚠ Connection error: {}", e);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    manager: &Mutex<TaskManager<Task>>,
+    storage: &FileStorage,
+) -> std::io::Result<()> {
+    let request = read_request(&mut stream)?;
+    let (status, body) = route(&request, manager, storage);
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())
+}
+
+fn read_request(stream: &mut TcpStream) -> std::io::Result<Request> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("GET").to_string();
+    let full_path = parts.next().unwrap_or("/").to_string();
+
+    let mut content_length = 0usize;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        if line.trim().is_empty() {
+            break;
+        }
+        let lower = line.to_lowercase();
+        if let Some(value) = lower.strip_prefix("content-length:") {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+
+    let mut raw_body = vec![0u8; content_length];
+    reader.read_exact(&mut raw_body)?;
+    let body = String::from_utf8_lossy(&raw_body).to_string();
+
+    let (path, query) = match full_path.split_once('?') {
+        Some((p, q)) => (p.to_string(), q.to_string()),
+        None => (full_path, String::new()),
+    };
+
+    Ok(Request {
+        method,
+        path,
+        query,
+        body,
+    })
+}
+
+fn route(req: &Request, manager: &Mutex<TaskManager<Task>>, storage: &FileStorage) -> (&'static str, String) {
+    match (req.method.as_str(), req.path.as_str()) {
+        ("GET", "/tasks") => handle_list(req, manager),
+        ("POST", "/tasks") => handle_create(req, manager, storage),
+        ("GET", "/stats") => handle_stats(manager),
+        (method, path) if path.starts_with("/tasks/") => {
+            let id_str = &path["/tasks/".len()..];
+            match id_str.parse::<u32>() {
+                Ok(id) => match method {
+                    "GET" => handle_show(id, manager),
+                    "PATCH" => handle_update(id, req, manager, storage),
+                    "DELETE" => handle_delete(id, manager, storage),
+                    _ => ("405 Method Not Allowed", error_json("Method not allowed")),
+                },
+                Err(_) => ("400 Bad Request", error_json("Invalid task ID")),
+            }
+        }
+        _ => ("404 Not Found", error_json("Not found")),
+    }
+}
+
+fn status_for_error(err: &TaskError) -> &'static str {
+    match err {
+        TaskError::NotFound(_) => "404 Not Found",
+        TaskError::ValidationError(_)
+        | TaskError::ParseError(_)
+        | TaskError::InvalidPriority(_)
+        | TaskError::InvalidStatus(_)
+        | TaskError::SerializationError(_) => "400 Bad Request",
+        TaskError::BlockedByDependency(_) | TaskError::DependencyCycle(_) => "409 Conflict",
+        TaskError::IoError(_) | TaskError::IntegrityError(_) => "500 Internal Server Error",
+    }
+}
+
+fn parse_query(query: &str) -> Vec<(&str, &str)> {
+    query
+        .split('&')
+        .filter(|s| !s.is_empty())
+        .filter_map(|pair| pair.split_once('='))
+        .collect()
+}
+
+fn handle_list(req: &Request, manager: &Mutex<TaskManager<Task>>) -> (&'static str, String) {
+    let mgr = manager.lock().unwrap();
+    let filters = parse_query(&req.query);
+
+    let tasks: Vec<&Task> = mgr
+        .list_tasks()
+        .iter()
+        .filter(|t| {
+            for (key, value) in &filters {
+                match *key {
+                    "status" => match Status::from_str(value) {
+                        Ok(s) if t.status == s => {}
+                        _ => return false,
+                    },
+                    "priority" => match Priority::from_str(value) {
+                        Ok(p) if t.priority == p => {}
+                        _ => return false,
+                    },
+                    "category" => {
+                        if t.category.as_deref() != Some(*value) {
+                            return false;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            true
+        })
+        .collect();
+
+    ("200 OK", tasks_to_json_array(&tasks))
+}
+
+fn handle_show(id: u32, manager: &Mutex<TaskManager<Task>>) -> (&'static str, String) {
+    let mgr = manager.lock().unwrap();
+    match mgr.get_task_by_id(id) {
+        Some(task) => ("200 OK", task_to_json(task)),
+        None => (
+            "404 Not Found",
+            error_json(&TaskError::NotFound(id).to_string()),
+        ),
+    }
+}
+
+fn handle_create(
+    req: &Request,
+    manager: &Mutex<TaskManager<Task>>,
+    storage: &FileStorage,
+) -> (&'static str, String) {
+    let title = extract_field(&req.body, "title").unwrap_or("").to_string();
+    let priority = extract_field(&req.body, "priority")
+        .and_then(|p| Priority::from_str(p).ok())
+        .unwrap_or(Priority::Medium);
+    let category = extract_field(&req.body, "category").map(|c| c.to_string());
+
+    let mut mgr = manager.lock().unwrap();
+    let id = mgr.peek_next_id();
+
+    let task = match Task::new(id, title, priority) {
+        Ok(mut task) => {
+            if let Some(cat) = category {
+                task = task.with_category(cat);
+            }
+            task
+        }
+        Err(e) => return (status_for_error(&e), error_json(&e.to_string())),
+    };
+
+    let body = task_to_json(&task);
+    if let Err(e) = mgr.dispatch(TaskAction::Add(task)) {
+        return (status_for_error(&e), error_json(&e.to_string()));
+    }
+    let _ = storage.save(mgr.list_tasks());
+
+    ("201 Created", body)
+}
+
+fn handle_update(
+    id: u32,
+    req: &Request,
+    manager: &Mutex<TaskManager<Task>>,
+    storage: &FileStorage,
+) -> (&'static str, String) {
+    let mut mgr = manager.lock().unwrap();
+    let mut updated = match mgr.get_task_by_id(id) {
+        Some(task) => task.clone(),
+        None => {
+            return (
+                "404 Not Found",
+                error_json(&TaskError::NotFound(id).to_string()),
+            )
+        }
+    };
+
+    if let Some(title) = extract_field(&req.body, "title") {
+        updated.title = title.to_string();
+    }
+    if let Some(status) = extract_field(&req.body, "status").and_then(|s| Status::from_str(s).ok()) {
+        updated.status = status;
+    }
+    if let Some(priority) = extract_field(&req.body, "priority").and_then(|p| Priority::from_str(p).ok()) {
+        updated.priority = priority;
+    }
+    if let Some(category) = extract_field(&req.body, "category") {
+        updated.category = Some(category.to_string());
+    }
+
+    let body = task_to_json(&updated);
+    if let Err(e) = mgr.dispatch(TaskAction::Update(updated)) {
+        return (status_for_error(&e), error_json(&e.to_string()));
+    }
+    let _ = storage.save(mgr.list_tasks());
+
+    ("200 OK", body)
+}
+
+fn handle_delete(
+    id: u32,
+    manager: &Mutex<TaskManager<Task>>,
+    storage: &FileStorage,
+) -> (&'static str, String) {
+    let mut mgr = manager.lock().unwrap();
+    match mgr.dispatch(TaskAction::Delete(id)) {
+        Ok(()) => {
+            let _ = storage.save(mgr.list_tasks());
+            ("200 OK", "{}".to_string())
+        }
+        Err(e) => (status_for_error(&e), error_json(&e.to_string())),
+    }
+}
+
+fn handle_stats(manager: &Mutex<TaskManager<Task>>) -> (&'static str, String) {
+    let mgr = manager.lock().unwrap();
+    let tasks = mgr.list_tasks();
+    let total = tasks.len();
+    let pending = tasks.iter().filter(|t| t.status == Status::Pending).count();
+    let in_progress = tasks
+        .iter()
+        .filter(|t| t.status == Status::InProgress)
+        .count();
+    let completed = tasks
+        .iter()
+        .filter(|t| t.status == Status::Completed)
+        .count();
+
+    let body = format!(
+        "{{\"total\":{},\"pending\":{},\"in_progress\":{},\"completed\":{}}}",
+        total, pending, in_progress, completed
+    );
+
+    ("200 OK", body)
+}
+
+fn tasks_to_json_array(tasks: &[&Task]) -> String {
+    let items: Vec<String> = tasks.iter().map(|t| task_to_json(t)).collect();
+    format!("[{}]", items.join(","))
+}
+
+fn error_json(message: &str) -> String {
+    format!("{{\"error\":\"{}\"}}", message.replace('"', "'"))
+}
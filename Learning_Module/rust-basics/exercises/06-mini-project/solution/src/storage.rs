@@ -1,15 +1,17 @@
 // Storage module for persisting tasks to file
 
 use crate::error::TaskError;
-use crate::task::{Serializable, Task};
+use crate::task::{Priority, Serializable, Status, Task};
 use std::fs;
 use std::path::Path;
+use std::str::FromStr;
 
 pub trait Storage<T> {
     fn save(&self, items: &[T]) -> Result<(), TaskError>;
     fn load(&self) -> Result<Vec<T>, TaskError>;
 }
 
+#[derive(Clone)]
 pub struct FileStorage {
     file_path: String,
 }
@@ -21,10 +23,18 @@ impl FileStorage {
 }
 
 impl Storage<Task> for FileStorage {
+    /// Writes tasks followed by a trailing `#hash:<hex>` footer covering the
+    /// task block, so `load` can detect truncation or hand-edits. The write
+    /// itself goes to a sibling temp file and is `fs::rename`d into place so
+    /// a crash mid-write never leaves `file_path` holding a partial file.
     fn save(&self, items: &[Task]) -> Result<(), TaskError> {
         let serialized: Vec<String> = items.iter().map(|task| task.serialize()).collect();
-        let content = serialized.join("\n");
-        fs::write(&self.file_path, content)?;
+        let body = serialized.join("\n");
+        let content = format!("{}\n#hash:{}\n", body, content_hash(&body));
+
+        let tmp_path = format!("{}.tmp", self.file_path);
+        fs::write(&tmp_path, content)?;
+        fs::rename(&tmp_path, &self.file_path)?;
         Ok(())
     }
 
@@ -34,9 +44,24 @@ impl Storage<Task> for FileStorage {
         }
 
         let content = fs::read_to_string(&self.file_path)?;
-        let mut tasks = Vec::new();
+        let body = match content.trim_end().rsplit_once('\n') {
+            Some((body, footer)) if footer.starts_with("#hash:") => {
+                let expected = &footer["#hash:".len()..];
+                if content_hash(body) != expected {
+                    return Err(TaskError::IntegrityError(format!(
+                        "checksum mismatch in {} (file may be corrupted or hand-edited)",
+                        self.file_path
+                    )));
+                }
+                body
+            }
+            // Files written before integrity hashing was added have no
+            // footer - load them as-is rather than rejecting them.
+            _ => content.trim_end(),
+        };
 
-        for line in content.lines() {
+        let mut tasks = Vec::new();
+        for line in body.lines() {
             if line.trim().is_empty() {
                 continue;
             }
@@ -47,3 +72,192 @@ impl Storage<Task> for FileStorage {
         Ok(tasks)
     }
 }
+
+/// A small non-cryptographic stable hash (FNV-1a) used to detect accidental
+/// corruption of the task file - no integrity/crypto crate is available here.
+fn content_hash(content: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Reads/writes tasks as a Taskwarrior-compatible JSON array, so tasks can be
+/// exchanged with the wider Taskwarrior ecosystem instead of only this
+/// crate's pipe-delimited format. There's no `serde_json` dependency here, so
+/// encoding/decoding is done by hand against the fixed schema below.
+pub struct JsonStorage {
+    file_path: String,
+}
+
+impl JsonStorage {
+    pub fn new(file_path: String) -> Self {
+        JsonStorage { file_path }
+    }
+}
+
+impl Storage<Task> for JsonStorage {
+    fn save(&self, items: &[Task]) -> Result<(), TaskError> {
+        let objects: Vec<String> = items.iter().map(task_to_json).collect();
+        let content = format!("[\n  {}\n]\n", objects.join(",\n  "));
+        fs::write(&self.file_path, content)?;
+        Ok(())
+    }
+
+    fn load(&self) -> Result<Vec<Task>, TaskError> {
+        if !Path::new(&self.file_path).exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.file_path)?;
+        parse_json_array(&content)
+            .into_iter()
+            .map(|obj| json_to_task(&obj))
+            .collect()
+    }
+}
+
+pub(crate) fn escape_json(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+pub(crate) fn unescape_json(s: &str) -> String {
+    s.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+pub(crate) fn task_to_json(task: &Task) -> String {
+    let status = match task.status {
+        Status::Pending => "pending",
+        Status::InProgress => "pending",
+        Status::Completed => "completed",
+    };
+    let tags = task
+        .category
+        .as_ref()
+        .map(|c| format!("\"{}\"", escape_json(c)))
+        .unwrap_or_default();
+
+    format!(
+        "{{\"uuid\":\"{}\",\"description\":\"{}\",\"status\":\"{}\",\"priority\":\"{}\",\"entry\":\"{}\",\"tags\":[{}]}}",
+        task.id,
+        escape_json(&task.title),
+        status,
+        task.priority,
+        escape_json(&task.created_at),
+        tags
+    )
+}
+
+/// Splits a top-level JSON array of flat objects into their raw `{...}` bodies.
+/// Only tracks brace and (backslash-escape-aware) quote nesting - good enough
+/// for this crate's own export format, not a general-purpose JSON parser.
+fn parse_json_array(content: &str) -> Vec<String> {
+    let trimmed = content.trim();
+    let inner = trimmed
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(trimmed);
+
+    let mut objects = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut current = String::new();
+
+    for c in inner.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth -= 1,
+            _ => {}
+        }
+
+        if depth > 0 || (c == '}' && depth == 0) {
+            current.push(c);
+        }
+
+        if depth == 0 && c == '}' {
+            objects.push(current.clone());
+            current.clear();
+        }
+    }
+
+    objects
+}
+
+/// Extracts the raw value for `key` from a flat `{"key":"value", ...}` object,
+/// handling both quoted strings and bracketed arrays as values.
+pub(crate) fn extract_field<'a>(obj: &'a str, key: &str) -> Option<&'a str> {
+    let needle = format!("\"{}\":", key);
+    let start = obj.find(&needle)? + needle.len();
+    let rest = &obj[start..];
+
+    if let Some(stripped) = rest.strip_prefix('"') {
+        // The string ends at the first quote not escaped by a backslash.
+        let mut escaped = false;
+        let end = stripped.char_indices().find_map(|(i, c)| {
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => return Some(i),
+                _ => {}
+            }
+            None
+        })?;
+        Some(&stripped[..end])
+    } else if let Some(stripped) = rest.strip_prefix('[') {
+        let end = stripped.find(']')?;
+        Some(&stripped[..end])
+    } else {
+        let end = rest.find(',').unwrap_or(rest.len());
+        Some(rest[..end].trim_end_matches('}').trim())
+    }
+}
+
+fn json_to_task(obj: &str) -> Result<Task, TaskError> {
+    let uuid = extract_field(obj, "uuid")
+        .ok_or_else(|| TaskError::SerializationError("Missing 'uuid' field".to_string()))?;
+    let id: u32 = uuid
+        .parse()
+        .map_err(|_| TaskError::ParseError(format!("Invalid uuid: {}", uuid)))?;
+
+    let description = extract_field(obj, "description")
+        .ok_or_else(|| TaskError::SerializationError("Missing 'description' field".to_string()))?;
+    let title = unescape_json(description);
+
+    let status_str = extract_field(obj, "status").unwrap_or("pending");
+    let status = match status_str {
+        "pending" | "waiting" => Status::Pending,
+        "completed" => Status::Completed,
+        "deleted" => Status::Completed, // deleted tasks carry no separate status here
+        other => Status::from_str(other).unwrap_or(Status::Pending),
+    };
+
+    let priority = extract_field(obj, "priority")
+        .and_then(|p| Priority::from_str(p).ok())
+        .unwrap_or(Priority::Medium);
+
+    let entry = extract_field(obj, "entry").map(unescape_json);
+
+    let tags = extract_field(obj, "tags").unwrap_or("");
+    let category = tags
+        .split(',')
+        .map(|t| t.trim().trim_matches('"'))
+        .find(|t| !t.is_empty())
+        .map(|t| t.to_string());
+
+    let mut task = Task::new(id, title, priority)?;
+    task.set_status(status);
+    if let Some(cat) = category {
+        task = task.with_category(cat);
+    }
+    if let Some(entry) = entry {
+        task.created_at = entry;
+    }
+
+    Ok(task)
+}
@@ -1,6 +1,8 @@
 // Task-related types: Task, Priority, Status
 
+use crate::date::{Date, RecurRule};
 use crate::error::TaskError;
+use crate::storage::{escape_json, extract_field, unescape_json};
 use std::fmt;
 use std::str::FromStr;
 
@@ -76,6 +78,12 @@ pub struct Task {
     pub status: Status,
     pub category: Option<String>,
     pub created_at: String,
+    pub depends_on: Vec<u32>,
+    pub due: Option<Date>,
+    pub recur: Option<RecurRule>,
+    // Ticks remaining before the task is auto-flagged overdue; None if it
+    // doesn't decay.
+    pub staleness: Option<u32>,
 }
 
 impl Task {
@@ -94,6 +102,10 @@ impl Task {
             status: Status::Pending,
             category: None,
             created_at: Self::current_timestamp(),
+            depends_on: Vec::new(),
+            due: None,
+            recur: None,
+            staleness: None,
         })
     }
 
@@ -107,6 +119,33 @@ impl Task {
         self
     }
 
+    pub fn with_depends_on(mut self, depends_on: Vec<u32>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+
+    pub fn with_due(mut self, due: Date) -> Self {
+        self.due = Some(due);
+        self
+    }
+
+    pub fn with_recur(mut self, recur: RecurRule) -> Self {
+        self.recur = Some(recur);
+        self
+    }
+
+    pub fn with_staleness(mut self, staleness: u32) -> Self {
+        self.staleness = Some(staleness);
+        self
+    }
+
+    pub fn is_overdue(&self, today: Date) -> bool {
+        match self.due {
+            Some(due) => !self.is_completed() && due < today,
+            None => false,
+        }
+    }
+
     pub fn set_status(&mut self, status: Status) {
         self.status = status;
     }
@@ -147,51 +186,321 @@ pub trait Serializable {
         Self: Sized;
 }
 
+/// Exposes a stable numeric id for generic containers like `TaskManager<T>`
+/// that need to look items up by id without knowing the concrete type.
+pub trait Identifiable {
+    fn id(&self) -> u32;
+}
+
+impl Identifiable for Task {
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+/// Escapes `\` and `|` in a single pipe-format field so embedded pipes don't
+/// get mistaken for field separators on deserialize.
+fn escape_field(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if ch == '\\' || ch == '|' {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Splits a pipe-format record on unescaped `|`, unescaping `\|` and `\\` in
+/// the process. The inverse of joining fields produced by `escape_field`.
+fn split_escaped_fields(data: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = data.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            '|' => fields.push(std::mem::take(&mut current)),
+            _ => current.push(ch),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
 impl Serializable for Task {
     fn serialize(&self) -> String {
         let desc = self
             .description
-            .as_ref()
-            .map(|d| d.as_str())
-            .unwrap_or("None");
+            .as_deref()
+            .map(escape_field)
+            .unwrap_or_else(|| "None".to_string());
         let cat = self
             .category
-            .as_ref()
-            .map(|c| c.as_str())
-            .unwrap_or("None");
+            .as_deref()
+            .map(escape_field)
+            .unwrap_or_else(|| "None".to_string());
+        let depends_on = if self.depends_on.is_empty() {
+            "None".to_string()
+        } else {
+            self.depends_on
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let due = self
+            .due
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| "None".to_string());
+        let recur = self
+            .recur
+            .map(|r| r.to_string())
+            .unwrap_or_else(|| "None".to_string());
+        let staleness = self
+            .staleness
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "None".to_string());
 
         format!(
-            "{}|{}|{}|{}|{}|{}|{}",
-            self.id, self.title, desc, self.priority, self.status, cat, self.created_at
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.id,
+            escape_field(&self.title),
+            desc,
+            self.priority,
+            self.status,
+            cat,
+            escape_field(&self.created_at),
+            depends_on,
+            due,
+            recur,
+            staleness
         )
     }
 
+    /// Detects the record's format and dispatches: a leading `{` means the
+    /// JSON format from `serialize_json`, otherwise the legacy pipe format.
     fn deserialize(data: &str) -> Result<Self, TaskError> {
-        let parts: Vec<&str> = data.split('|').collect();
-        if parts.len() != 7 {
+        if data.trim_start().starts_with('{') {
+            Task::deserialize_json(data)
+        } else {
+            Task::deserialize_pipe(data)
+        }
+    }
+}
+
+impl Task {
+    fn deserialize_pipe(data: &str) -> Result<Task, TaskError> {
+        let mut parts = split_escaped_fields(data);
+        // Records written before depends_on/due/recur/staleness were added
+        // carry only the first 7 to 10 fields; the missing ones read as None.
+        if (7..11).contains(&parts.len()) {
+            parts.resize(11, "None".to_string());
+        }
+        if parts.len() != 11 {
             return Err(TaskError::SerializationError(format!(
-                "Expected 7 fields, got {}",
+                "Expected 7 to 11 pipe-delimited fields, got {}",
                 parts.len()
             )));
         }
 
-        let id = parts[0]
-            .parse()
-            .map_err(|_| TaskError::ParseError("Invalid ID".to_string()))?;
-        let title = parts[1].to_string();
+        let id = parts[0].parse().map_err(|_| {
+            TaskError::SerializationError(format!("Invalid 'id' field: {}", parts[0]))
+        })?;
+        let title = parts[1].clone();
         let description = if parts[2] == "None" {
             None
         } else {
-            Some(parts[2].to_string())
+            Some(parts[2].clone())
         };
         let priority = parts[3].parse()?;
         let status = parts[4].parse()?;
         let category = if parts[5] == "None" {
             None
         } else {
-            Some(parts[5].to_string())
+            Some(parts[5].clone())
+        };
+        let created_at = parts[6].clone();
+        let depends_on = if parts[7] == "None" {
+            Vec::new()
+        } else {
+            parts[7]
+                .split(',')
+                .map(|id| {
+                    id.parse().map_err(|_| {
+                        TaskError::SerializationError(format!(
+                            "Invalid 'depends_on' field: {}",
+                            id
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<u32>, TaskError>>()?
+        };
+        let due = if parts[8] == "None" {
+            None
+        } else {
+            Some(parts[8].parse()?)
+        };
+        let recur = if parts[9] == "None" {
+            None
+        } else {
+            Some(parts[9].parse()?)
         };
-        let created_at = parts[6].to_string();
+        let staleness = if parts[10] == "None" {
+            None
+        } else {
+            Some(parts[10].parse().map_err(|_| {
+                TaskError::SerializationError(format!("Invalid 'staleness' field: {}", parts[10]))
+            })?)
+        };
+
+        Ok(Task {
+            id,
+            title,
+            description,
+            priority,
+            status,
+            category,
+            created_at,
+            depends_on,
+            due,
+            recur,
+            staleness,
+        })
+    }
+
+    /// Serializes every field of `self` to a JSON object, for lossless
+    /// interchange with other tools (unlike `storage::JsonStorage`'s
+    /// Taskwarrior-compatible export, which only carries a subset of
+    /// fields). There's no `serde_json` dependency here, so encoding is done
+    /// by hand against this fixed schema, reusing `storage`'s JSON escaping.
+    pub fn serialize_json(&self) -> String {
+        let description = self
+            .description
+            .as_deref()
+            .map(|d| format!("\"{}\"", escape_json(d)))
+            .unwrap_or_else(|| "null".to_string());
+        let category = self
+            .category
+            .as_deref()
+            .map(|c| format!("\"{}\"", escape_json(c)))
+            .unwrap_or_else(|| "null".to_string());
+        let depends_on = format!(
+            "[{}]",
+            self.depends_on
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        let due = self
+            .due
+            .map(|d| format!("\"{}\"", d))
+            .unwrap_or_else(|| "null".to_string());
+        let recur = self
+            .recur
+            .map(|r| format!("\"{}\"", r))
+            .unwrap_or_else(|| "null".to_string());
+        let staleness = self
+            .staleness
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "null".to_string());
+
+        format!(
+            "{{\"id\":{},\"title\":\"{}\",\"description\":{},\"priority\":\"{}\",\"status\":\"{}\",\"category\":{},\"created_at\":\"{}\",\"depends_on\":{},\"due\":{},\"recur\":{},\"staleness\":{}}}",
+            self.id,
+            escape_json(&self.title),
+            description,
+            self.priority,
+            self.status,
+            category,
+            escape_json(&self.created_at),
+            depends_on,
+            due,
+            recur,
+            staleness
+        )
+    }
+
+    /// Parses the JSON object produced by `serialize_json`.
+    pub fn deserialize_json(data: &str) -> Result<Task, TaskError> {
+        let id_str = extract_field(data, "id")
+            .ok_or_else(|| TaskError::SerializationError("JSON task missing 'id' field".to_string()))?;
+        let id = id_str.parse().map_err(|_| {
+            TaskError::SerializationError(format!("Invalid 'id' field: {}", id_str))
+        })?;
+
+        let title = extract_field(data, "title")
+            .map(unescape_json)
+            .ok_or_else(|| {
+                TaskError::SerializationError("JSON task missing 'title' field".to_string())
+            })?;
+
+        let description = extract_field(data, "description")
+            .filter(|s| *s != "null")
+            .map(unescape_json);
+
+        let priority_str = extract_field(data, "priority").ok_or_else(|| {
+            TaskError::SerializationError("JSON task missing 'priority' field".to_string())
+        })?;
+        let priority = priority_str.parse()?;
+
+        let status_str = extract_field(data, "status").ok_or_else(|| {
+            TaskError::SerializationError("JSON task missing 'status' field".to_string())
+        })?;
+        let status = status_str.parse()?;
+
+        let category = extract_field(data, "category")
+            .filter(|s| *s != "null")
+            .map(unescape_json);
+
+        let created_at = extract_field(data, "created_at")
+            .map(unescape_json)
+            .ok_or_else(|| {
+                TaskError::SerializationError("JSON task missing 'created_at' field".to_string())
+            })?;
+
+        let depends_on = extract_field(data, "depends_on")
+            .map(|s| {
+                s.split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(|id| {
+                        id.parse().map_err(|_| {
+                            TaskError::SerializationError(format!(
+                                "Invalid 'depends_on' field: {}",
+                                id
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<u32>, TaskError>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let due: Option<Date> = extract_field(data, "due")
+            .filter(|s| *s != "null")
+            .map(|s| s.parse())
+            .transpose()?;
+
+        let recur: Option<RecurRule> = extract_field(data, "recur")
+            .filter(|s| *s != "null")
+            .map(|s| s.parse())
+            .transpose()?;
+
+        let staleness = extract_field(data, "staleness")
+            .filter(|s| *s != "null")
+            .map(|s| {
+                s.parse().map_err(|_| {
+                    TaskError::SerializationError(format!("Invalid 'staleness' field: {}", s))
+                })
+            })
+            .transpose()?;
 
         Ok(Task {
             id,
@@ -201,6 +510,10 @@ impl Serializable for Task {
             status,
             category,
             created_at,
+            depends_on,
+            due,
+            recur,
+            staleness,
         })
     }
 }